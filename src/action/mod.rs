@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::ebi::{EbiOutcome, EbiPosition};
+use crate::bjj_match::match_result::WinMethod;
+use crate::bjj_match::match_time::MatchTime;
+use crate::bjj_match::stoppage::StoppageKind;
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+    AddPoints(CompetitorNumber, usize),
+    SubtractPoint(CompetitorNumber),
+    AddAdvantage(CompetitorNumber),
+    SubtractAdvantage(CompetitorNumber),
+    AddPenalty(CompetitorNumber),
+    SubtractPenalty(CompetitorNumber),
+    Win(CompetitorNumber, WinMethod),
+    RecordResult,
+    StartStop,
+    NewMatch,
+    Undo,
+    Redo,
+    ToggleFullscreen,
+    AdjustClock(isize),
+    SetRemainingTime(usize),
+    ToggleStoppage(CompetitorNumber, StoppageKind),
+    SetEbiPosition(EbiPosition),
+    RecordEbiOutcome(EbiOutcome)
+}
+
+impl Action {
+    pub fn all() -> Vec<Action> {
+        let mut actions = vec![Action::StartStop, Action::RecordResult, Action::NewMatch, Action::Undo, Action::Redo, Action::ToggleFullscreen];
+        actions.extend([-10, -1, 1, 10].map(Action::AdjustClock));
+        actions.extend(EbiPosition::iter().map(Action::SetEbiPosition));
+        actions.extend([EbiOutcome::Submission, EbiOutcome::Escape].map(Action::RecordEbiOutcome));
+
+        for competitor in [CompetitorNumber::One, CompetitorNumber::Two] {
+            actions.extend([
+                Action::AddPoints(competitor, 2),
+                Action::AddPoints(competitor, 3),
+                Action::AddPoints(competitor, 4),
+                Action::AddAdvantage(competitor),
+                Action::AddPenalty(competitor),
+                Action::SubtractPoint(competitor),
+                Action::SubtractAdvantage(competitor),
+                Action::SubtractPenalty(competitor),
+            ]);
+            actions.extend(WinMethod::manual().map(|method| Action::Win(competitor, method)));
+            actions.extend(StoppageKind::iter().map(|kind| Action::ToggleStoppage(competitor, kind)));
+        }
+
+        actions
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::all().into_iter().find(|action| action.name() == name)
+    }
+
+    pub fn name(&self) -> String {
+        let competitor_name = |competitor: &CompetitorNumber| match competitor {
+            CompetitorNumber::One => "competitor_one",
+            CompetitorNumber::Two => "competitor_two"
+        };
+
+        match self {
+            Action::AddPoints(competitor, points) => format!("{}_add_{}_points", competitor_name(competitor), points),
+            Action::SubtractPoint(competitor) => format!("{}_subtract_point", competitor_name(competitor)),
+            Action::AddAdvantage(competitor) => format!("{}_add_advantage", competitor_name(competitor)),
+            Action::SubtractAdvantage(competitor) => format!("{}_subtract_advantage", competitor_name(competitor)),
+            Action::AddPenalty(competitor) => format!("{}_add_penalty", competitor_name(competitor)),
+            Action::SubtractPenalty(competitor) => format!("{}_subtract_penalty", competitor_name(competitor)),
+            Action::Win(competitor, method) => format!("{}_wins_by_{}", competitor_name(competitor), method.get_name()),
+            Action::RecordResult => "record_result".to_owned(),
+            Action::StartStop => "start_stop".to_owned(),
+            Action::NewMatch => "new_match".to_owned(),
+            Action::Undo => "undo".to_owned(),
+            Action::Redo => "redo".to_owned(),
+            Action::ToggleFullscreen => "toggle_fullscreen".to_owned(),
+            Action::AdjustClock(seconds) if *seconds < 0 => format!("clock_subtract_{}_seconds", seconds.unsigned_abs()),
+            Action::AdjustClock(seconds) => format!("clock_add_{}_seconds", seconds),
+            Action::SetRemainingTime(millis) => format!("clock_set_remaining_{}_millis", millis),
+            Action::ToggleStoppage(competitor, kind) => format!("{}_{}_timer", competitor_name(competitor), kind.get_name()),
+            Action::SetEbiPosition(position) => format!("ebi_position_{}", position.get_name()),
+            Action::RecordEbiOutcome(outcome) => format!("ebi_{}", outcome.get_name())
+        }
+    }
+
+    pub fn get_display_name(&self) -> String {
+        let competitor_name = |competitor: &CompetitorNumber| match competitor {
+            CompetitorNumber::One => "Competitor One",
+            CompetitorNumber::Two => "Competitor Two"
+        };
+
+        match self {
+            Action::AddPoints(competitor, points) => format!("{}: +{} Points", competitor_name(competitor), points),
+            Action::SubtractPoint(competitor) => format!("{}: Remove Point", competitor_name(competitor)),
+            Action::AddAdvantage(competitor) => format!("{}: Add Advantage", competitor_name(competitor)),
+            Action::SubtractAdvantage(competitor) => format!("{}: Remove Advantage", competitor_name(competitor)),
+            Action::AddPenalty(competitor) => format!("{}: Add Penalty", competitor_name(competitor)),
+            Action::SubtractPenalty(competitor) => format!("{}: Remove Penalty", competitor_name(competitor)),
+            Action::Win(competitor, method) => format!("{}: Wins by {}", competitor_name(competitor), method.get_display_name()),
+            Action::RecordResult => "Record Result".to_owned(),
+            Action::StartStop => "Start / Stop".to_owned(),
+            Action::NewMatch => "New Match".to_owned(),
+            Action::Undo => "Undo".to_owned(),
+            Action::Redo => "Redo".to_owned(),
+            Action::ToggleFullscreen => "Toggle Fullscreen".to_owned(),
+            Action::AdjustClock(seconds) => format!("Clock: {:+} Seconds", seconds),
+            Action::SetRemainingTime(millis) => format!("Clock: Set Remaining to {}", MatchTime::format_millis(*millis)),
+            Action::ToggleStoppage(competitor, kind) => format!("{}: {} Timer", competitor_name(competitor), kind.get_display_name()),
+            Action::SetEbiPosition(position) => format!("EBI: Start from {}", position.get_display_name()),
+            Action::RecordEbiOutcome(outcome) => format!("EBI: {}", outcome.get_display_name())
+        }
+    }
+}
@@ -10,8 +10,21 @@ pub struct Audio {
 
 impl Audio {
     pub fn init(&mut self) {
-        let (stream, stream_handle) = OutputStream::try_default().unwrap();
-        self.sink = Some(Sink::try_new(&stream_handle).unwrap());
+        let (stream, stream_handle) = match OutputStream::try_default() {
+            Ok(output) => output,
+            Err(e) => {
+                println!("Error opening audio output: {}", e);
+                return;
+            }
+        };
+        let sink = match Sink::try_new(&stream_handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                println!("Error creating audio sink: {}", e);
+                return;
+            }
+        };
+        self.sink = Some(sink);
         self.stream = Some(stream);
         self.stream_handle = Some(stream_handle);
     }
@@ -1,12 +1,22 @@
+use serde::{Deserialize, Serialize};
 use crate::flags::Country;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CompetitorNumber {
     One,
     Two
 }
 
-#[derive(Debug)]
+impl CompetitorNumber {
+    pub fn opponent(&self) -> CompetitorNumber {
+        match self {
+            CompetitorNumber::One => CompetitorNumber::Two,
+            CompetitorNumber::Two => CompetitorNumber::One
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Competitor {
     pub first_name: String,
     pub last_name: String,
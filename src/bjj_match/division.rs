@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Gender {
+    #[default]
+    Unspecified,
+    Male,
+    Female
+}
+
+impl Gender {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            Gender::Unspecified => "",
+            Gender::Male => "Male",
+            Gender::Female => "Female"
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Belt {
+    #[default]
+    Unspecified,
+    White,
+    Grey,
+    Yellow,
+    Orange,
+    Green,
+    Blue,
+    Purple,
+    Brown,
+    Black
+}
+
+impl Belt {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            Belt::Unspecified => "",
+            Belt::White => "White Belt",
+            Belt::Grey => "Grey Belt",
+            Belt::Yellow => "Yellow Belt",
+            Belt::Orange => "Orange Belt",
+            Belt::Green => "Green Belt",
+            Belt::Blue => "Blue Belt",
+            Belt::Purple => "Purple Belt",
+            Belt::Brown => "Brown Belt",
+            Belt::Black => "Black Belt"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Division {
+    pub age_class: String,
+    pub gender: Gender,
+    pub belt: Belt,
+    pub weight_class: String
+}
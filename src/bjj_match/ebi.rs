@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use strum_macros::EnumIter;
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::match_time::MatchTime;
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum EbiPosition {
+    #[default]
+    BackControl,
+    SpiderWeb
+}
+
+impl EbiPosition {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EbiPosition::BackControl => "back_control",
+            EbiPosition::SpiderWeb => "spider_web"
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            EbiPosition::BackControl => "Back Control",
+            EbiPosition::SpiderWeb => "Spider Web"
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum EbiOutcome {
+    Submission,
+    Escape,
+    TimeExpired
+}
+
+impl EbiOutcome {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EbiOutcome::Submission => "submission",
+            EbiOutcome::Escape => "escape",
+            EbiOutcome::TimeExpired => "time_expired"
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            EbiOutcome::Submission => "Submission",
+            EbiOutcome::Escape => "Escape",
+            EbiOutcome::TimeExpired => "Time Expired"
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EbiTurn {
+    pub attacker: CompetitorNumber,
+    pub position: EbiPosition,
+    pub outcome: Option<EbiOutcome>,
+    pub time_millis: usize
+}
+
+impl EbiTurn {
+    pub fn new(attacker: CompetitorNumber) -> EbiTurn {
+        EbiTurn {
+            attacker,
+            position: EbiPosition::default(),
+            outcome: None,
+            time_millis: 0
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EbiRound {
+    pub turns: Vec<EbiTurn>
+}
+
+impl EbiRound {
+    pub fn is_complete(&self) -> bool {
+        self.turns.len() == 2 && self.turns.iter().all(|turn| turn.outcome.is_some())
+    }
+
+    pub fn get_turn(&self, attacker: CompetitorNumber) -> Option<&EbiTurn> {
+        self.turns.iter().find(|turn| turn.attacker == attacker)
+    }
+
+    pub fn get_submission_millis(&self, competitor: CompetitorNumber) -> Option<usize> {
+        self.get_turn(competitor)
+            .filter(|turn| turn.outcome == Some(EbiOutcome::Submission))
+            .map(|turn| turn.time_millis)
+    }
+
+    pub fn get_escape_millis(&self, competitor: CompetitorNumber) -> Option<usize> {
+        self.get_turn(competitor.opponent())
+            .filter(|turn| turn.outcome.is_some_and(|outcome| outcome != EbiOutcome::Submission))
+            .map(|turn| turn.time_millis)
+    }
+
+    pub fn get_summary(&self, competitor: CompetitorNumber) -> Option<String> {
+        if let Some(millis) = self.get_submission_millis(competitor) {
+            return Some(format!("Sub {}", MatchTime::format_millis(millis)));
+        }
+
+        let defending = self.get_turn(competitor.opponent())?;
+        match defending.outcome? {
+            EbiOutcome::Submission => Some("Tapped".to_owned()),
+            EbiOutcome::Escape => Some(format!("Esc {}", MatchTime::format_millis(defending.time_millis))),
+            EbiOutcome::TimeExpired => Some(format!("Held {}", MatchTime::format_millis(defending.time_millis)))
+        }
+    }
+
+    pub fn decide_submission(&self) -> Option<(CompetitorNumber, usize)> {
+        if !self.is_complete() {
+            return None;
+        }
+
+        let one = CompetitorNumber::One;
+        let two = CompetitorNumber::Two;
+        match (self.get_submission_millis(one), self.get_submission_millis(two)) {
+            (Some(one_millis), Some(two_millis)) => match one_millis.cmp(&two_millis) {
+                Ordering::Less => Some((one, one_millis)),
+                Ordering::Greater => Some((two, two_millis)),
+                Ordering::Equal => None
+            },
+            (Some(millis), None) => Some((one, millis)),
+            (None, Some(millis)) => Some((two, millis)),
+            (None, None) => None
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EbiOvertime {
+    pub rounds: Vec<EbiRound>
+}
+
+impl EbiOvertime {
+    pub fn is_active(&self) -> bool {
+        !self.rounds.is_empty()
+    }
+
+    pub fn get_current_turn(&self) -> Option<&EbiTurn> {
+        self.rounds.last()?.turns.last().filter(|turn| turn.outcome.is_none())
+    }
+
+    pub fn get_current_turn_mut(&mut self) -> Option<&mut EbiTurn> {
+        self.rounds.last_mut()?.turns.last_mut().filter(|turn| turn.outcome.is_none())
+    }
+
+    pub fn start_round(&mut self) {
+        let attacker = match self.rounds.len() % 2 {
+            0 => CompetitorNumber::One,
+            _ => CompetitorNumber::Two
+        };
+        self.rounds.push(EbiRound { turns: vec![EbiTurn::new(attacker)] });
+    }
+
+    pub fn get_escape_millis(&self, competitor: CompetitorNumber) -> usize {
+        self.rounds.iter().filter_map(|round| round.get_escape_millis(competitor)).sum()
+    }
+
+    pub fn decide_escape_time(&self) -> Option<(CompetitorNumber, usize)> {
+        if !self.rounds.iter().all(EbiRound::is_complete) {
+            return None;
+        }
+
+        let one = self.get_escape_millis(CompetitorNumber::One);
+        let two = self.get_escape_millis(CompetitorNumber::Two);
+        match one.cmp(&two) {
+            Ordering::Less => Some((CompetitorNumber::One, one)),
+            Ordering::Greater => Some((CompetitorNumber::Two, two)),
+            Ordering::Equal => None
+        }
+    }
+
+    pub fn get_summary(&self, competitor: CompetitorNumber) -> Vec<String> {
+        self.rounds.iter()
+            .enumerate()
+            .filter_map(|(index, round)| round.get_summary(competitor).map(|summary| format!("R{} {}", index + 1, summary)))
+            .collect()
+    }
+
+    pub fn start_second_turn(&mut self) {
+        if let Some(round) = self.rounds.last_mut() {
+            if let Some(attacker) = round.turns.first().map(|turn| turn.attacker.opponent()) {
+                round.turns.push(EbiTurn::new(attacker));
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::ebi::{EbiOutcome, EbiPosition};
+use crate::bjj_match::match_information::MatchInformation;
+use crate::bjj_match::match_result::WinMethod;
+use crate::bjj_match::match_score::MatchScore;
+use crate::bjj_match::match_time::MatchTime;
+use crate::bjj_match::player_score::ScoreField;
+use crate::bjj_match::stoppage::StoppageKind;
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MatchEventKind {
+    AddPoints(CompetitorNumber, usize),
+    SubtractPoint(CompetitorNumber),
+    AddAdvantage(CompetitorNumber),
+    SubtractAdvantage(CompetitorNumber),
+    AddPenalty(CompetitorNumber),
+    SubtractPenalty(CompetitorNumber),
+    ClockStarted,
+    ClockStopped,
+    MatchFinished(CompetitorNumber, WinMethod),
+    ClockAdjusted(usize, usize),
+    StoppageStarted(CompetitorNumber, StoppageKind),
+    StoppageStopped(CompetitorNumber, StoppageKind),
+    OvertimeStarted(usize),
+    EbiTurnFinished(CompetitorNumber, EbiPosition, EbiOutcome, usize)
+}
+
+impl MatchEventKind {
+    pub fn is_scoring(&self) -> bool {
+        !matches!(self, MatchEventKind::ClockStarted | MatchEventKind::ClockStopped | MatchEventKind::ClockAdjusted(..)
+            | MatchEventKind::StoppageStarted(..) | MatchEventKind::StoppageStopped(..) | MatchEventKind::OvertimeStarted(_))
+    }
+
+    pub fn get_description(&self, info: &MatchInformation) -> String {
+        let name = |competitor: &CompetitorNumber| info.get_competitor(*competitor).get_display_name();
+
+        match self {
+            MatchEventKind::AddPoints(competitor, points) => format!("{} points to {}", points, name(competitor)),
+            MatchEventKind::SubtractPoint(competitor) => format!("Point removed from {}", name(competitor)),
+            MatchEventKind::AddAdvantage(competitor) => format!("Advantage to {}", name(competitor)),
+            MatchEventKind::SubtractAdvantage(competitor) => format!("Advantage removed from {}", name(competitor)),
+            MatchEventKind::AddPenalty(competitor) => format!("Penalty to {}", name(competitor)),
+            MatchEventKind::SubtractPenalty(competitor) => format!("Penalty removed from {}", name(competitor)),
+            MatchEventKind::ClockStarted => "Clock started".to_owned(),
+            MatchEventKind::ClockStopped => "Clock stopped".to_owned(),
+            MatchEventKind::MatchFinished(competitor, method) => format!("{} wins by {}", name(competitor), method.get_display_name()),
+            MatchEventKind::ClockAdjusted(from, to) => format!("Clock adjusted from {} to {} remaining", MatchTime::format_millis(*from), MatchTime::format_millis(*to)),
+            MatchEventKind::StoppageStarted(competitor, kind) => format!("{} timer started for {}", kind.get_display_name(), name(competitor)),
+            MatchEventKind::StoppageStopped(competitor, kind) => format!("{} timer stopped for {}", kind.get_display_name(), name(competitor)),
+            MatchEventKind::OvertimeStarted(period) => format!("{} started", info.overtime.get_period_name(*period)),
+            MatchEventKind::EbiTurnFinished(attacker, position, outcome, millis) => match outcome {
+                EbiOutcome::Submission => format!("{} submitted {} from {} in {}", name(attacker), name(&attacker.opponent()), position.get_display_name(), MatchTime::format_millis(*millis)),
+                EbiOutcome::Escape => format!("{} escaped {} in {}", name(&attacker.opponent()), position.get_display_name(), MatchTime::format_millis(*millis)),
+                EbiOutcome::TimeExpired => format!("{} held in {} for {}", name(&attacker.opponent()), position.get_display_name(), MatchTime::format_millis(*millis))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchEvent {
+    pub timestamp: SystemTime,
+    pub match_time_millis: usize,
+    pub kind: MatchEventKind
+}
+
+impl MatchEvent {
+    pub fn get_description(&self, info: &MatchInformation) -> String {
+        format!("{} {}", MatchTime::format_millis(self.match_time_millis), self.kind.get_description(info))
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct MatchLog {
+    pub events: Vec<MatchEvent>,
+    pub undone: Vec<MatchEvent>
+}
+
+impl MatchLog {
+    pub fn record(&mut self, event: MatchEvent) {
+        if event.kind.is_scoring() {
+            self.undone.clear();
+        }
+        self.events.push(event);
+    }
+
+    pub fn undo(&mut self) -> bool {
+        match self.events.iter().rposition(|event| event.kind.is_scoring()) {
+            Some(index) => {
+                let event = self.events.remove(index);
+                self.undone.push(event);
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn redo(&mut self) -> bool {
+        match self.undone.pop() {
+            Some(event) => {
+                self.events.push(event);
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn score(&self) -> MatchScore {
+        let mut score = MatchScore::default();
+
+        for event in &self.events {
+            match event.kind {
+                MatchEventKind::AddPoints(competitor, points) => score.get_competitor_score_mut(competitor).points += points,
+                MatchEventKind::SubtractPoint(competitor) => score.get_competitor_score_mut(competitor).subtract(ScoreField::Points),
+                MatchEventKind::AddAdvantage(competitor) => score.get_competitor_score_mut(competitor).advantages += 1,
+                MatchEventKind::SubtractAdvantage(competitor) => score.get_competitor_score_mut(competitor).subtract(ScoreField::Advantages),
+                MatchEventKind::AddPenalty(competitor) => score.get_competitor_score_mut(competitor).penalties += 1,
+                MatchEventKind::SubtractPenalty(competitor) => score.get_competitor_score_mut(competitor).subtract(ScoreField::Penalties),
+                MatchEventKind::ClockStarted | MatchEventKind::ClockStopped | MatchEventKind::MatchFinished(..) | MatchEventKind::ClockAdjusted(..)
+                    | MatchEventKind::StoppageStarted(..) | MatchEventKind::StoppageStopped(..) | MatchEventKind::OvertimeStarted(_)
+                    | MatchEventKind::EbiTurnFinished(..) => {}
+            }
+        }
+
+        score
+    }
+}
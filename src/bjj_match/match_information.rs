@@ -1,12 +1,22 @@
-use crate::bjj_match::competitor::Competitor;
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::{Competitor, CompetitorNumber};
+use crate::bjj_match::division::Division;
+use crate::bjj_match::ruleset::{Overtime, RulesetKind};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchInformation {
     pub competitor_one: Competitor,
     pub competitor_two: Competitor,
     pub match_time_minutes: usize,
     pub mat_number: usize,
-    pub fight_number: usize
+    pub fight_number: usize,
+    pub ruleset: RulesetKind,
+    #[serde(default)]
+    pub overtime: Overtime,
+    #[serde(default)]
+    pub division: Division,
+    #[serde(default)]
+    pub round: String
 }
 
 impl Default for MatchInformation {
@@ -22,7 +32,37 @@ impl Default for MatchInformation {
             },
             match_time_minutes: 5,
             mat_number: 1,
-            fight_number: 1
+            fight_number: 1,
+            ruleset: RulesetKind::default(),
+            overtime: Overtime::default(),
+            division: Division::default(),
+            round: String::new()
         }
     }
 }
+
+impl MatchInformation {
+    pub fn get_competitor(&self, competitor: CompetitorNumber) -> &Competitor {
+        match competitor {
+            CompetitorNumber::One => &self.competitor_one,
+            CompetitorNumber::Two => &self.competitor_two
+        }
+    }
+
+    pub fn render_template(&self, template: &str) -> String {
+        let rendered = template
+            .replace("{mat}", &self.mat_number.to_string())
+            .replace("{fight}", &self.fight_number.to_string())
+            .replace("{age}", &self.division.age_class)
+            .replace("{gender}", self.division.gender.get_display_name())
+            .replace("{belt}", self.division.belt.get_display_name())
+            .replace("{weight}", &self.division.weight_class)
+            .replace("{round}", &self.round);
+
+        rendered.split('·')
+            .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
@@ -0,0 +1,62 @@
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::CompetitorNumber;
+
+#[derive(Debug, Copy, Clone, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum WinMethod {
+    Submission,
+    Points,
+    Advantages,
+    Penalties,
+    RefereeDecision,
+    Disqualification,
+    Walkover,
+    Injury,
+    EscapeTime
+}
+
+impl WinMethod {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            WinMethod::Submission => "submission",
+            WinMethod::Points => "points",
+            WinMethod::Advantages => "advantages",
+            WinMethod::Penalties => "penalties",
+            WinMethod::RefereeDecision => "referee_decision",
+            WinMethod::Disqualification => "disqualification",
+            WinMethod::Walkover => "walkover",
+            WinMethod::Injury => "injury",
+            WinMethod::EscapeTime => "escape_time"
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            WinMethod::Submission => "Submission",
+            WinMethod::Points => "Points",
+            WinMethod::Advantages => "Advantages",
+            WinMethod::Penalties => "Fewer Penalties",
+            WinMethod::RefereeDecision => "Referee Decision",
+            WinMethod::Disqualification => "Disqualification",
+            WinMethod::Walkover => "Walkover",
+            WinMethod::Injury => "Injury",
+            WinMethod::EscapeTime => "Fastest Escape"
+        }
+    }
+
+    pub fn is_manual(&self) -> bool {
+        *self != WinMethod::EscapeTime
+    }
+
+    pub fn manual() -> impl Iterator<Item = WinMethod> {
+        WinMethod::iter().filter(WinMethod::is_manual)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchResult {
+    pub winner: CompetitorNumber,
+    pub method: WinMethod,
+    pub time_elapsed_millis: usize
+}
@@ -1,8 +1,26 @@
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::CompetitorNumber;
 use crate::bjj_match::player_score::PlayerScore;
 
-#[derive(Default ,Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct MatchScore {
     pub competitor_one_score: PlayerScore,
     pub competitor_two_score: PlayerScore,
     pub time_remaining_milliseconds: usize,
-}
\ No newline at end of file
+}
+
+impl MatchScore {
+    pub fn get_competitor_score(&self, competitor: CompetitorNumber) -> &PlayerScore {
+        match competitor {
+            CompetitorNumber::One => &self.competitor_one_score,
+            CompetitorNumber::Two => &self.competitor_two_score
+        }
+    }
+
+    pub fn get_competitor_score_mut(&mut self, competitor: CompetitorNumber) -> &mut PlayerScore {
+        match competitor {
+            CompetitorNumber::One => &mut self.competitor_one_score,
+            CompetitorNumber::Two => &mut self.competitor_two_score
+        }
+    }
+}
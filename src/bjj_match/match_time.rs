@@ -1,16 +1,101 @@
-use std::time::{Duration, SystemTime};
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Debug)]
+pub trait Clock: Debug + Send + Sync {
+    fn now_millis(&self) -> u64;
+}
+
+#[derive(Debug, Default)]
+pub struct MonotonicClock;
+
+impl Clock for MonotonicClock {
+    fn now_millis(&self) -> u64 {
+        static EPOCH: OnceLock<Instant> = OnceLock::new();
+        EPOCH.get_or_init(Instant::now).elapsed().as_millis() as u64
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    millis: AtomicU64,
+}
+
+impl ManualClock {
+    pub fn advance(&self, millis: u64) {
+        self.millis.fetch_add(millis, Ordering::SeqCst);
+    }
+
+    pub fn set(&self, millis: u64) {
+        self.millis.store(millis, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_millis(&self) -> u64 {
+        self.millis.load(Ordering::SeqCst)
+    }
+}
+
+fn default_clock() -> Arc<dyn Clock> {
+    Arc::new(MonotonicClock)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchTime {
     pub duration_millis: usize,
-    pub last_started: Option<SystemTime>,
+    #[serde(default)]
+    pub started: bool,
     pub time_elapsed_millis: usize,
-    pub running: bool
+    #[serde(skip_deserializing)]
+    pub running: bool,
+    #[serde(skip)]
+    running_since: Option<u64>,
+    #[serde(skip, default = "default_clock")]
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for MatchTime {
+    fn default() -> Self {
+        Self {
+            duration_millis: 0,
+            started: false,
+            time_elapsed_millis: 0,
+            running: false,
+            running_since: None,
+            clock: default_clock(),
+        }
+    }
 }
 
 impl MatchTime {
+    pub fn new(duration_millis: usize) -> MatchTime {
+        MatchTime {
+            duration_millis,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_clock(duration_millis: usize, clock: Arc<dyn Clock>) -> MatchTime {
+        MatchTime {
+            duration_millis,
+            clock,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
     pub fn get_remaining_time_string(&self) -> String {
-        let millis = self.get_remaining_time_milliseconds();
+        MatchTime::format_millis(self.get_remaining_time_milliseconds())
+    }
+
+    pub fn format_millis(millis: usize) -> String {
         let hours = millis / 3_600_000;
         let minutes = (millis % 3_600_000) / 60_000;
         let seconds = (millis % 60_000) / 1_000;
@@ -23,24 +108,32 @@ impl MatchTime {
         }
     }
 
-    pub fn get_remaining_time_milliseconds(&self) -> usize {
-        let elapsed = match &self.last_started {
-            Some(start_time) => {
-                match self.running {
-                    true => {
-                        self.time_elapsed_millis + SystemTime::now().duration_since(*start_time).unwrap_or(Duration::new(0, 0)).as_millis() as usize
-                    },
-                    false => {
-                        self.time_elapsed_millis
-                    }
-                }
-            },
-            None => {
-                self.time_elapsed_millis
-            }
+    pub fn parse_millis(text: &str) -> Option<usize> {
+        let text = text.trim();
+        let (minutes, seconds) = match text.split_once(':') {
+            Some((minutes, seconds)) => (minutes.parse::<usize>().ok()?, seconds),
+            None => (0, text)
         };
+        let millis = seconds.parse::<f64>().ok()
+            .map(|seconds| (seconds * 1000.0).round())
+            .filter(|millis| (0.0..usize::MAX as f64).contains(millis))?;
 
-        self.duration_millis.saturating_sub(elapsed)
+        minutes.checked_mul(60_000)?.checked_add(millis as usize)
+    }
+
+    pub fn get_remaining_time_milliseconds(&self) -> usize {
+        self.duration_millis.saturating_sub(self.get_elapsed_time_milliseconds())
+    }
+
+    pub fn get_elapsed_time_milliseconds(&self) -> usize {
+        self.time_elapsed_millis + self.get_running_millis()
+    }
+
+    fn get_running_millis(&self) -> usize {
+        match self.running_since {
+            Some(since) if self.running => self.clock.now_millis().saturating_sub(since) as usize,
+            _ => 0
+        }
     }
 
     pub fn toggle_start_stop(&mut self) {
@@ -57,7 +150,20 @@ impl MatchTime {
         }
 
         self.running = true;
-        self.last_started = Some(SystemTime::now());
+        self.started = true;
+        self.running_since = Some(self.clock.now_millis());
+    }
+
+    pub fn set_remaining_time_milliseconds(&mut self, millis: usize) {
+        let running = self.running;
+        self.stop();
+
+        self.duration_millis = self.duration_millis.max(millis);
+        self.time_elapsed_millis = self.duration_millis - millis;
+
+        if running {
+            self.start();
+        }
     }
 
     pub fn stop(&mut self) {
@@ -65,14 +171,101 @@ impl MatchTime {
             return;
         }
 
-        let elapsed = match &self.last_started {
-            Some(start_time) => {
-                SystemTime::now().duration_since(*start_time).unwrap_or(Duration::new(0,0)).as_millis() as usize
-            },
-            None => 0
-        };
-
+        self.time_elapsed_millis += self.get_running_millis();
         self.running = false;
-        self.time_elapsed_millis += elapsed;
+        self.running_since = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bjj_match::{BJJMatch, MatchState};
+
+    fn manual_time(duration_millis: usize) -> (Arc<ManualClock>, MatchTime) {
+        let clock = Arc::new(ManualClock::default());
+        let time = MatchTime::with_clock(duration_millis, clock.clone());
+        (clock, time)
+    }
+
+    #[test]
+    fn counts_down_to_zero() {
+        let (clock, mut time) = manual_time(10_000);
+        time.start();
+        clock.advance(4_000);
+        assert_eq!(time.get_remaining_time_milliseconds(), 6_000);
+
+        clock.advance(8_000);
+        assert_eq!(time.get_remaining_time_milliseconds(), 0);
+        assert_eq!(time.get_elapsed_time_milliseconds(), 12_000);
+    }
+
+    #[test]
+    fn holds_time_while_paused() {
+        let (clock, mut time) = manual_time(10_000);
+        time.start();
+        clock.advance(3_000);
+        time.stop();
+        clock.advance(5_000);
+        assert!(!time.running);
+        assert_eq!(time.get_remaining_time_milliseconds(), 7_000);
+
+        time.toggle_start_stop();
+        clock.advance(2_000);
+        assert!(time.running);
+        assert_eq!(time.get_remaining_time_milliseconds(), 5_000);
+    }
+
+    #[test]
+    fn reports_match_state_from_the_clock() {
+        let (clock, time) = manual_time(60_000);
+        let mut bjj_match = BJJMatch { time, ..Default::default() };
+        assert_eq!(bjj_match.get_match_state(), MatchState::NotStarted);
+
+        bjj_match.time.start();
+        clock.advance(30_000);
+        assert_eq!(bjj_match.get_match_state(), MatchState::InProgress);
+
+        bjj_match.time.stop();
+        clock.advance(60_000);
+        assert_eq!(bjj_match.get_match_state(), MatchState::InProgress);
+
+        bjj_match.time.start();
+        clock.advance(30_000);
+        assert_eq!(bjj_match.get_match_state(), MatchState::Finished);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn restores_a_stopped_clock() {
+        let (clock, mut time) = manual_time(10_000);
+        time.start();
+        clock.advance(2_000);
+
+        let mut restored: MatchTime = serde_json::from_str(&serde_json::to_string(&time).unwrap()).unwrap();
+        assert!(!restored.running);
+        assert_eq!(restored.get_elapsed_time_milliseconds(), 0);
+
+        restored.start();
+        assert!(restored.running);
+    }
+
+    #[test]
+    fn keeps_elapsed_time_when_setting_remaining() {
+        let (clock, mut time) = manual_time(10_000);
+        time.start();
+        clock.advance(1_000);
+        time.set_remaining_time_milliseconds(4_000);
+        clock.advance(1_000);
+        assert!(time.running);
+        assert_eq!(time.get_remaining_time_milliseconds(), 3_000);
+    }
+
+    #[test]
+    fn parses_clock_text() {
+        assert_eq!(MatchTime::parse_millis("4:30"), Some(270_000));
+        assert_eq!(MatchTime::parse_millis("12.5"), Some(12_500));
+        assert_eq!(MatchTime::parse_millis("-1"), None);
+        assert_eq!(MatchTime::parse_millis("18446744073709551615:00"), None);
+        assert_eq!(MatchTime::parse_millis("1e300"), None);
+    }
+}
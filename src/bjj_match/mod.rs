@@ -3,49 +3,81 @@ pub mod competitor;
 pub mod match_score;
 pub mod match_time;
 pub mod player_score;
+pub mod match_result;
+pub mod ruleset;
+pub mod match_event;
+pub mod division;
+pub mod stoppage;
+pub mod ebi;
 
+use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
 use crate::bjj_match::competitor::{Competitor, CompetitorNumber};
+use crate::bjj_match::ebi::{EbiOutcome, EbiOvertime, EbiPosition};
 use crate::bjj_match::match_information::MatchInformation;
 use crate::bjj_match::match_score::MatchScore;
 use crate::bjj_match::match_time::MatchTime;
-use crate::bjj_match::player_score::ScoreField;
+use crate::bjj_match::match_result::{MatchResult, WinMethod};
+use crate::bjj_match::match_event::{MatchEvent, MatchEventKind, MatchLog};
+use crate::bjj_match::ruleset::{OvertimeMode, Ruleset, RulesetKind};
+use crate::bjj_match::stoppage::{StoppageKind, StoppageTimers};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum MatchState {
     NotStarted,
     InProgress,
+    AwaitingDecision,
     Finished
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct BJJMatch {
     pub info: MatchInformation,
-    pub score: MatchScore,
-    pub time: MatchTime
+    pub log: MatchLog,
+    pub time: MatchTime,
+    #[serde(default)]
+    pub stoppages: StoppageTimers,
+    pub result: Option<MatchResult>,
+    pub referee_decision_required: bool,
+    #[serde(default)]
+    pub overtime_period: usize,
+    #[serde(default)]
+    pub ebi: EbiOvertime
 }
 
 impl BJJMatch {
-    pub fn new(competitor_one: Competitor, competitor_two: Competitor, match_time_minutes: usize, mat_number: usize, fight_number: usize) -> BJJMatch {
+    pub fn new(competitor_one: Competitor, competitor_two: Competitor, match_time_minutes: usize, mat_number: usize, fight_number: usize, ruleset: RulesetKind) -> BJJMatch {
         BJJMatch{
             info: MatchInformation {
                 competitor_one,
                 competitor_two,
                 match_time_minutes,
                 mat_number,
-                fight_number
-            },
-            score: MatchScore::default(),
-            time: MatchTime {
-                duration_millis: match_time_minutes * 60 * 1000,
+                fight_number,
+                ruleset,
                 ..Default::default()
-            }
+            },
+            log: MatchLog::default(),
+            time: MatchTime::new(match_time_minutes * 60 * 1000),
+            stoppages: StoppageTimers::default(),
+            result: None,
+            referee_decision_required: false,
+            overtime_period: 0,
+            ebi: EbiOvertime::default()
         }
     }
 
     pub fn get_match_state(&self) -> MatchState {
-        match self.time.last_started {
-            None => MatchState::NotStarted,
-            _ => match self.time.get_remaining_time_milliseconds() {
+        if self.is_finished() {
+            return MatchState::Finished;
+        }
+        if self.referee_decision_required {
+            return MatchState::AwaitingDecision;
+        }
+
+        match self.time.started {
+            false => MatchState::NotStarted,
+            true => match self.time.get_remaining_time_milliseconds() {
                 0 => MatchState::Finished,
                 _ => MatchState::InProgress,
             },
@@ -53,53 +85,644 @@ impl BJJMatch {
     }
 
     pub fn add_points(&mut self, points: usize, competitor: CompetitorNumber) {
-        match competitor {
-            CompetitorNumber::One => self.score.competitor_one_score.points += points,
-            CompetitorNumber::Two => self.score.competitor_two_score.points += points
-        };
+        self.record_score(MatchEventKind::AddPoints(competitor, points));
     }
 
     pub fn add_advantage(&mut self, competitor: CompetitorNumber) {
-        match competitor {
-            CompetitorNumber::One => self.score.competitor_one_score.advantages += 1,
-            CompetitorNumber::Two => self.score.competitor_two_score.advantages += 1
-        };
+        self.record_score(MatchEventKind::AddAdvantage(competitor));
     }
 
     pub fn add_penalty(&mut self, competitor: CompetitorNumber) {
-        match competitor {
-            CompetitorNumber::One => self.score.competitor_one_score.penalties += 1,
-            CompetitorNumber::Two => self.score.competitor_two_score.penalties += 1
-        };
+        self.record_score(MatchEventKind::AddPenalty(competitor));
     }
 
     pub fn subtract_point(&mut self, competitor: CompetitorNumber) {
-        match competitor {
-            CompetitorNumber::One => self.score.competitor_one_score.subtract(ScoreField::Points),
-            CompetitorNumber::Two => self.score.competitor_two_score.subtract(ScoreField::Points)
-        };
+        self.record_score(MatchEventKind::SubtractPoint(competitor));
     }
 
     pub fn subtract_advantage(&mut self, competitor: CompetitorNumber) {
-        match competitor {
-            CompetitorNumber::One => self.score.competitor_one_score.subtract(ScoreField::Advantages),
-            CompetitorNumber::Two => self.score.competitor_two_score.subtract(ScoreField::Advantages)
-        };
+        self.record_score(MatchEventKind::SubtractAdvantage(competitor));
     }
 
     pub fn subtract_penalty(&mut self, competitor: CompetitorNumber) {
-        match competitor {
-            CompetitorNumber::One => self.score.competitor_one_score.subtract(ScoreField::Penalties),
-            CompetitorNumber::Two => self.score.competitor_two_score.subtract(ScoreField::Penalties)
+        self.record_score(MatchEventKind::SubtractPenalty(competitor));
+    }
+
+    pub fn undo(&mut self) {
+        let last_scoring = self.log.events.iter().rposition(|event| event.kind.is_scoring());
+        let last_kind = last_scoring.map(|index| self.log.events[index].kind);
+        if let (Some(index), Some(MatchEventKind::EbiTurnFinished(..))) = (last_scoring, last_kind) {
+            self.undo_ebi_turn(index);
+            return;
+        }
+
+        let overtime_started = self.log.events.iter().rposition(|event| matches!(event.kind, MatchEventKind::OvertimeStarted(_)));
+        if overtime_started > last_scoring {
+            return;
+        }
+
+        let manual_finish = matches!(last_kind, Some(MatchEventKind::MatchFinished(..)));
+        if self.is_golden_score_finish() {
+            self.result = None;
+        }
+        if self.is_frozen() && !manual_finish && !self.is_penalty_disqualification() {
+            return;
+        }
+
+        if self.log.undo() {
+            if manual_finish {
+                self.result = None;
+                self.referee_decision_required = false;
+            }
+            self.refresh_result();
+        }
+    }
+
+    pub fn redo(&mut self) {
+        let finishing = matches!(self.log.undone.last().map(|event| event.kind), Some(MatchEventKind::MatchFinished(..)));
+        if self.is_finished() || (self.is_frozen() && !finishing) {
+            return;
+        }
+
+        if let Some(MatchEventKind::EbiTurnFinished(attacker, ..)) = self.log.undone.last().map(|event| event.kind) {
+            if self.ebi.get_current_turn().map(|turn| turn.attacker) != Some(attacker) {
+                return;
+            }
+            self.stop();
+        }
+
+        if self.log.redo() {
+            self.refresh_result();
+            if let Some(kind) = self.log.events.last().map(|event| event.kind) {
+                self.check_golden_score(kind);
+                self.finish_ebi_turn(kind);
+                if let MatchEventKind::MatchFinished(winner, method) = kind {
+                    self.award(winner, method);
+                }
+            }
+        }
+    }
+
+    fn record(&mut self, kind: MatchEventKind) {
+        self.log.record(MatchEvent {
+            timestamp: SystemTime::now(),
+            match_time_millis: self.time.get_elapsed_time_milliseconds().min(self.time.duration_millis),
+            kind
+        });
+    }
+
+    fn record_score(&mut self, kind: MatchEventKind) {
+        let correcting_disqualification = matches!(kind, MatchEventKind::SubtractPenalty(_)) && self.is_penalty_disqualification();
+        if self.is_frozen() && !correcting_disqualification {
+            return;
+        }
+
+        self.record(kind);
+        self.refresh_result();
+        self.check_golden_score(kind);
+    }
+
+    fn check_golden_score(&mut self, kind: MatchEventKind) {
+        if let MatchEventKind::AddPoints(competitor, _) = kind {
+            if self.is_golden_score() {
+                self.award(competitor, WinMethod::Points);
+            }
+        }
+    }
+
+    fn is_golden_score_finish(&self) -> bool {
+        let Some(result) = &self.result else {
+            return false;
         };
+        let last_scoring = self.log.events.iter().rev().find(|event| event.kind.is_scoring());
+
+        self.is_golden_score() && result.method == WinMethod::Points
+            && last_scoring.is_some_and(|event| matches!(event.kind, MatchEventKind::AddPoints(competitor, _) if competitor == result.winner))
+    }
+
+    fn refresh_result(&mut self) {
+        let disqualified = self.ruleset().disqualified(&self.get_raw_score());
+
+        match &self.result {
+            Some(result) if result.method == WinMethod::Disqualification => {
+                if disqualified != Some(result.winner.opponent()) {
+                    self.result = None;
+                }
+            },
+            Some(_) => {},
+            None => {
+                if let Some(disqualified) = disqualified {
+                    self.award(disqualified.opponent(), WinMethod::Disqualification);
+                }
+            }
+        }
+    }
+
+    pub fn ruleset(&self) -> &'static dyn Ruleset {
+        self.info.ruleset.rules()
+    }
+
+    pub fn get_raw_score(&self) -> MatchScore {
+        self.log.score()
+    }
+
+    pub fn get_score(&self) -> MatchScore {
+        self.ruleset().derive_score(&self.get_raw_score())
+    }
+
+    fn is_penalty_disqualification(&self) -> bool {
+        match &self.result {
+            Some(result) => {
+                result.method == WinMethod::Disqualification
+                    && self.ruleset().disqualified(&self.get_raw_score()) == Some(result.winner.opponent())
+            },
+            None => false
+        }
     }
 
     pub fn start(&mut self) {
-        self.time.duration_millis = self.info.match_time_minutes * 60 * 1000;
-        self.time.start();
+        if self.overtime_period == 0 {
+            self.time.duration_millis = self.info.match_time_minutes * 60 * 1000;
+        }
+        self.start_clock();
+    }
+
+    pub fn stop(&mut self) {
+        if !self.time.running {
+            return;
+        }
+
+        self.time.stop();
+        self.record(MatchEventKind::ClockStopped);
     }
 
     pub fn toggle_start_stop(&mut self) {
-        self.time.toggle_start_stop();
+        if self.is_frozen() {
+            return;
+        }
+
+        if self.time.running {
+            self.stop();
+        } else {
+            self.start_clock();
+        }
     }
-}
\ No newline at end of file
+
+    fn start_clock(&mut self) {
+        if self.time.running {
+            return;
+        }
+
+        self.stop_stoppages();
+        self.time.start();
+        self.record(MatchEventKind::ClockStarted);
+    }
+
+    pub fn can_start_stoppage(&self, competitor: CompetitorNumber, kind: StoppageKind) -> bool {
+        self.time.started && !self.time.running && !self.is_frozen()
+            && self.stoppages.get_remaining_millis(competitor, kind) > 0
+    }
+
+    pub fn toggle_stoppage(&mut self, competitor: CompetitorNumber, kind: StoppageKind) {
+        if self.stoppages.is_running(competitor, kind) {
+            self.stop_stoppage(competitor, kind);
+        } else {
+            self.start_stoppage(competitor, kind);
+        }
+    }
+
+    pub fn start_stoppage(&mut self, competitor: CompetitorNumber, kind: StoppageKind) {
+        if !self.can_start_stoppage(competitor, kind) || self.stoppages.is_running(competitor, kind) {
+            return;
+        }
+
+        let clock = self.time.get_clock();
+        self.stoppages.get_or_insert(competitor, kind, clock).time.start();
+        self.record(MatchEventKind::StoppageStarted(competitor, kind));
+    }
+
+    pub fn stop_stoppage(&mut self, competitor: CompetitorNumber, kind: StoppageKind) {
+        if !self.stoppages.is_running(competitor, kind) {
+            return;
+        }
+
+        let clock = self.time.get_clock();
+        self.stoppages.get_or_insert(competitor, kind, clock).time.stop();
+        self.record(MatchEventKind::StoppageStopped(competitor, kind));
+    }
+
+    fn stop_stoppages(&mut self) {
+        let running: Vec<(CompetitorNumber, StoppageKind)> = self.stoppages.running()
+            .map(|timer| (timer.competitor, timer.kind))
+            .collect();
+        for (competitor, kind) in running {
+            self.stop_stoppage(competitor, kind);
+        }
+    }
+
+    pub fn expire_stoppages(&mut self) -> Option<(CompetitorNumber, StoppageKind)> {
+        let (competitor, kind) = self.stoppages.running()
+            .find(|timer| timer.time.get_remaining_time_milliseconds() == 0)
+            .map(|timer| (timer.competitor, timer.kind))?;
+
+        self.stop_stoppage(competitor, kind);
+        Some((competitor, kind))
+    }
+
+    pub fn can_adjust_clock(&self) -> bool {
+        self.time.started && !self.time.running && !self.is_finished()
+    }
+
+    pub fn adjust_clock(&mut self, seconds: isize) {
+        let remaining = self.time.get_remaining_time_milliseconds();
+        let change = seconds.unsigned_abs().saturating_mul(1000);
+        self.set_remaining_time(match seconds < 0 {
+            true => remaining.saturating_sub(change),
+            false => remaining.saturating_add(change)
+        });
+    }
+
+    pub fn set_remaining_time(&mut self, millis: usize) {
+        if !self.can_adjust_clock() {
+            return;
+        }
+
+        let before = self.time.get_remaining_time_milliseconds();
+        self.time.set_remaining_time_milliseconds(millis);
+        if millis == before {
+            return;
+        }
+
+        if millis > 0 {
+            self.referee_decision_required = false;
+        }
+        self.record(MatchEventKind::ClockAdjusted(before, millis));
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.result.is_some()
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_finished() || self.referee_decision_required
+    }
+
+    pub fn finish(&mut self, winner: CompetitorNumber, method: WinMethod) {
+        if self.is_finished() {
+            return;
+        }
+
+        self.award(winner, method);
+        self.record(MatchEventKind::MatchFinished(winner, method));
+    }
+
+    fn award(&mut self, winner: CompetitorNumber, method: WinMethod) {
+        let time_elapsed_millis = self.time.get_elapsed_time_milliseconds().min(self.time.duration_millis);
+        self.award_at(winner, method, time_elapsed_millis);
+    }
+
+    fn award_at(&mut self, winner: CompetitorNumber, method: WinMethod, time_elapsed_millis: usize) {
+        if self.is_finished() {
+            return;
+        }
+
+        self.stop();
+        self.stop_stoppages();
+        self.referee_decision_required = false;
+        self.result = Some(MatchResult {
+            winner,
+            method,
+            time_elapsed_millis
+        });
+    }
+
+    pub fn expire(&mut self) {
+        if self.is_frozen() {
+            return;
+        }
+        if self.ebi.get_current_turn().is_some() {
+            self.record_ebi_outcome(EbiOutcome::TimeExpired);
+            return;
+        }
+
+        self.stop();
+        match self.ruleset().decide(&self.get_raw_score()) {
+            Some((winner, method)) => self.award(winner, method),
+            None if self.info.overtime.allows_period(self.overtime_period + 1) => self.start_overtime(),
+            None => self.referee_decision_required = true
+        }
+    }
+
+    fn start_overtime(&mut self) {
+        self.overtime_period += 1;
+        self.time = MatchTime::with_clock(self.info.overtime.period_minutes * 60 * 1000, self.time.get_clock());
+        self.record(MatchEventKind::OvertimeStarted(self.overtime_period));
+
+        if self.is_ebi() {
+            self.ebi.start_round();
+            self.time.started = true;
+        } else {
+            self.start_clock();
+        }
+    }
+
+    pub fn is_ebi(&self) -> bool {
+        self.overtime_period > 0 && self.info.overtime.mode == OvertimeMode::Ebi
+    }
+
+    pub fn can_set_ebi_position(&self) -> bool {
+        !self.is_frozen() && !self.time.running && self.time.get_elapsed_time_milliseconds() == 0
+            && self.ebi.get_current_turn().is_some()
+    }
+
+    pub fn set_ebi_position(&mut self, position: EbiPosition) {
+        if !self.can_set_ebi_position() {
+            return;
+        }
+
+        if let Some(turn) = self.ebi.get_current_turn_mut() {
+            turn.position = position;
+        }
+    }
+
+    pub fn record_ebi_outcome(&mut self, outcome: EbiOutcome) {
+        if self.is_frozen() || self.ebi.get_current_turn().is_none() {
+            return;
+        }
+
+        self.stop();
+        let time_millis = self.time.get_elapsed_time_milliseconds().min(self.time.duration_millis);
+        let Some(turn) = self.ebi.get_current_turn() else {
+            return;
+        };
+        let kind = MatchEventKind::EbiTurnFinished(turn.attacker, turn.position, outcome, time_millis);
+        self.record(kind);
+        self.finish_ebi_turn(kind);
+    }
+
+    fn finish_ebi_turn(&mut self, kind: MatchEventKind) {
+        let MatchEventKind::EbiTurnFinished(_, position, outcome, time_millis) = kind else {
+            return;
+        };
+        let Some(turn) = self.ebi.get_current_turn_mut() else {
+            return;
+        };
+        turn.position = position;
+        turn.outcome = Some(outcome);
+        turn.time_millis = time_millis;
+
+        let Some(round) = self.ebi.rounds.last() else {
+            return;
+        };
+        if !round.is_complete() {
+            self.time = MatchTime::with_clock(self.info.overtime.period_minutes * 60 * 1000, self.time.get_clock());
+            self.time.started = true;
+            self.ebi.start_second_turn();
+            return;
+        }
+
+        if let Some((winner, millis)) = round.decide_submission() {
+            self.award_at(winner, WinMethod::Submission, millis);
+        } else if self.info.overtime.allows_period(self.overtime_period + 1) {
+            self.start_overtime();
+        } else if let Some((winner, millis)) = self.ebi.decide_escape_time() {
+            self.award_at(winner, WinMethod::EscapeTime, millis);
+        } else {
+            self.referee_decision_required = true;
+        }
+    }
+
+    fn undo_ebi_turn(&mut self, index: usize) {
+        let MatchEventKind::EbiTurnFinished(_, position, _, time_millis) = self.log.events[index].kind else {
+            return;
+        };
+        self.stop();
+        if !self.log.undo() {
+            return;
+        }
+
+        let later = self.log.events.split_off(index);
+        self.log.events.extend(later.into_iter().filter(|event| !matches!(event.kind, MatchEventKind::OvertimeStarted(_))));
+
+        self.stop_stoppages();
+        self.result = None;
+        self.referee_decision_required = false;
+        self.overtime_period = self.log.events.iter()
+            .filter(|event| matches!(event.kind, MatchEventKind::OvertimeStarted(_)))
+            .count();
+        self.ebi = self.replay_ebi();
+        if let Some(turn) = self.ebi.get_current_turn_mut() {
+            turn.position = position;
+        }
+
+        self.time = MatchTime::with_clock(self.info.overtime.period_minutes * 60 * 1000, self.time.get_clock());
+        self.time.started = true;
+        self.time.time_elapsed_millis = time_millis;
+    }
+
+    fn replay_ebi(&self) -> EbiOvertime {
+        let mut ebi = EbiOvertime::default();
+        for event in &self.log.events {
+            match event.kind {
+                MatchEventKind::OvertimeStarted(_) if self.info.overtime.mode == OvertimeMode::Ebi => ebi.start_round(),
+                MatchEventKind::EbiTurnFinished(_, position, outcome, time_millis) => {
+                    if let Some(turn) = ebi.get_current_turn_mut() {
+                        turn.position = position;
+                        turn.outcome = Some(outcome);
+                        turn.time_millis = time_millis;
+                    }
+                    if ebi.rounds.last().is_some_and(|round| !round.is_complete()) {
+                        ebi.start_second_turn();
+                    }
+                },
+                _ => {}
+            }
+        }
+        ebi
+    }
+
+    pub fn is_golden_score(&self) -> bool {
+        self.overtime_period > 0 && self.info.overtime.mode == OvertimeMode::GoldenScore
+    }
+
+    pub fn get_period_name(&self) -> Option<String> {
+        match self.overtime_period {
+            0 => None,
+            period => Some(self.info.overtime.get_period_name(period))
+        }
+    }
+
+    pub fn get_ebi_turn_description(&self) -> Option<String> {
+        let turn = self.ebi.get_current_turn()?;
+        Some(format!("{} attacks from {}",
+            self.info.get_competitor(turn.attacker).get_display_name(),
+            turn.position.get_display_name()))
+    }
+
+    pub fn record_referee_decision(&mut self, winner: CompetitorNumber) {
+        if !self.referee_decision_required {
+            return;
+        }
+
+        self.finish(winner, WinMethod::RefereeDecision);
+    }
+
+    pub fn reset(&mut self) {
+        self.log = MatchLog::default();
+        self.time = MatchTime::with_clock(0, self.time.get_clock());
+        self.stoppages = StoppageTimers::default();
+        self.result = None;
+        self.referee_decision_required = false;
+        self.overtime_period = 0;
+        self.ebi = EbiOvertime::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::*;
+    use crate::bjj_match::match_time::ManualClock;
+    use crate::bjj_match::ruleset::Overtime;
+
+    fn new_match() -> BJJMatch {
+        BJJMatch::new(Competitor::default(), Competitor::default(), 5, 1, 1, RulesetKind::Ibjjf)
+    }
+
+    fn overtime_match(mode: OvertimeMode) -> (Arc<ManualClock>, BJJMatch) {
+        let clock = Arc::new(ManualClock::default());
+        let mut bjj_match = BJJMatch::new(Competitor::default(), Competitor::default(), 1, 1, 1, RulesetKind::Ibjjf);
+        bjj_match.info.overtime = Overtime { mode, period_minutes: 1, max_periods: 2 };
+        bjj_match.time = MatchTime::with_clock(60_000, clock.clone());
+        bjj_match.start();
+        clock.advance(60_000);
+        bjj_match.expire();
+        (clock, bjj_match)
+    }
+
+    fn decision(bjj_match: &BJJMatch) -> Option<(CompetitorNumber, WinMethod)> {
+        bjj_match.result.as_ref().map(|result| (result.winner, result.method))
+    }
+
+    #[test]
+    fn manual_result_is_logged_and_can_be_undone() {
+        let mut bjj_match = new_match();
+        bjj_match.add_points(2, CompetitorNumber::One);
+        bjj_match.finish(CompetitorNumber::Two, WinMethod::Submission);
+        assert!(bjj_match.is_frozen());
+        assert_eq!(bjj_match.log.events.last().map(|event| event.kind),
+            Some(MatchEventKind::MatchFinished(CompetitorNumber::Two, WinMethod::Submission)));
+
+        bjj_match.undo();
+        assert!(!bjj_match.is_frozen());
+        assert_eq!(bjj_match.get_raw_score().competitor_one_score.points, 2);
+
+        bjj_match.redo();
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::Two, WinMethod::Submission)));
+    }
+
+    #[test]
+    fn referee_decision_can_be_undone() {
+        let mut bjj_match = new_match();
+        bjj_match.expire();
+        bjj_match.record_referee_decision(CompetitorNumber::One);
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::One, WinMethod::RefereeDecision)));
+
+        bjj_match.undo();
+        assert!(!bjj_match.is_frozen());
+        bjj_match.finish(CompetitorNumber::Two, WinMethod::Walkover);
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::Two, WinMethod::Walkover)));
+    }
+
+    #[test]
+    fn golden_score_finishes_only_on_points() {
+        let (_, mut bjj_match) = overtime_match(OvertimeMode::GoldenScore);
+        assert!(bjj_match.is_golden_score());
+
+        bjj_match.add_advantage(CompetitorNumber::One);
+        bjj_match.add_penalty(CompetitorNumber::Two);
+        assert!(!bjj_match.is_finished());
+
+        bjj_match.add_points(2, CompetitorNumber::Two);
+        let result = bjj_match.result.as_ref().unwrap();
+        assert_eq!((result.winner, result.method), (CompetitorNumber::Two, WinMethod::Points));
+    }
+
+    #[test]
+    fn golden_score_finish_can_be_undone() {
+        let (_, mut bjj_match) = overtime_match(OvertimeMode::GoldenScore);
+        bjj_match.add_points(2, CompetitorNumber::One);
+        assert!(bjj_match.is_finished());
+
+        bjj_match.undo();
+        assert!(!bjj_match.is_finished());
+        assert_eq!(bjj_match.get_raw_score().competitor_one_score.points, 0);
+
+        bjj_match.redo();
+        assert_eq!(bjj_match.result.as_ref().map(|result| result.winner), Some(CompetitorNumber::One));
+    }
+
+    #[test]
+    fn ebi_submission_can_be_undone_and_redone() {
+        let (clock, mut bjj_match) = overtime_match(OvertimeMode::Ebi);
+        bjj_match.set_ebi_position(EbiPosition::SpiderWeb);
+        bjj_match.start();
+        clock.advance(20_000);
+        bjj_match.record_ebi_outcome(EbiOutcome::Submission);
+        bjj_match.start();
+        clock.advance(10_000);
+        bjj_match.record_ebi_outcome(EbiOutcome::Submission);
+        assert_eq!(bjj_match.result.as_ref().map(|result| result.winner), Some(CompetitorNumber::Two));
+
+        bjj_match.undo();
+        assert!(!bjj_match.is_frozen());
+        let turn = bjj_match.ebi.get_current_turn().unwrap();
+        assert_eq!(turn.attacker, CompetitorNumber::Two);
+        assert_eq!(bjj_match.time.get_elapsed_time_milliseconds(), 10_000);
+
+        bjj_match.undo();
+        let turn = bjj_match.ebi.get_current_turn().unwrap();
+        assert_eq!((turn.attacker, turn.position), (CompetitorNumber::One, EbiPosition::SpiderWeb));
+        assert_eq!(bjj_match.ebi.rounds[0].turns.len(), 1);
+
+        bjj_match.redo();
+        bjj_match.redo();
+        assert_eq!(bjj_match.result.as_ref().map(|result| (result.winner, result.time_elapsed_millis)), Some((CompetitorNumber::Two, 10_000)));
+    }
+
+    #[test]
+    fn ebi_escape_undo_returns_to_the_previous_round() {
+        let (clock, mut bjj_match) = overtime_match(OvertimeMode::Ebi);
+        for _ in 0..2 {
+            bjj_match.start();
+            clock.advance(5_000);
+            bjj_match.record_ebi_outcome(EbiOutcome::Escape);
+        }
+        assert_eq!(bjj_match.overtime_period, 2);
+        assert_eq!(bjj_match.ebi.rounds.len(), 2);
+
+        bjj_match.undo();
+        assert_eq!(bjj_match.overtime_period, 1);
+        assert_eq!(bjj_match.ebi.rounds.len(), 1);
+        assert_eq!(bjj_match.ebi.get_current_turn().map(|turn| turn.attacker), Some(CompetitorNumber::Two));
+    }
+
+    #[test]
+    fn ebi_time_expired_turn_can_be_undone() {
+        let (clock, mut bjj_match) = overtime_match(OvertimeMode::Ebi);
+        bjj_match.start();
+        clock.advance(60_000);
+        bjj_match.expire();
+        assert_eq!(bjj_match.ebi.get_current_turn().map(|turn| turn.attacker), Some(CompetitorNumber::Two));
+
+        bjj_match.undo();
+        let turn = bjj_match.ebi.get_current_turn().unwrap();
+        assert_eq!((turn.attacker, turn.outcome), (CompetitorNumber::One, None));
+        assert_eq!(bjj_match.time.get_elapsed_time_milliseconds(), 60_000);
+        assert!(bjj_match.log.undone.iter().any(|event| matches!(event.kind, MatchEventKind::EbiTurnFinished(_, _, EbiOutcome::TimeExpired, _))));
+
+        bjj_match.redo();
+        assert_eq!(bjj_match.ebi.get_current_turn().map(|turn| turn.attacker), Some(CompetitorNumber::Two));
+        assert_eq!(bjj_match.ebi.rounds[0].turns[0].outcome, Some(EbiOutcome::TimeExpired));
+    }
+}
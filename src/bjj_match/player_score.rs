@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, PartialEq)]
 pub enum ScoreField {
     Points,
@@ -5,7 +7,7 @@ pub enum ScoreField {
     Penalties
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerScore {
     pub points: usize,
     pub advantages: usize,
@@ -0,0 +1,302 @@
+use std::cmp::Ordering;
+use strum_macros::EnumIter;
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::match_result::WinMethod;
+use crate::bjj_match::match_score::MatchScore;
+use crate::bjj_match::player_score::PlayerScore;
+
+pub trait Ruleset {
+    fn derive_score(&self, raw: &MatchScore) -> MatchScore;
+    fn disqualified(&self, raw: &MatchScore) -> Option<CompetitorNumber>;
+
+    fn decide(&self, raw: &MatchScore) -> Option<(CompetitorNumber, WinMethod)> {
+        let score = self.derive_score(raw);
+        let one = &score.competitor_one_score;
+        let two = &score.competitor_two_score;
+
+        let tiebreaks = [
+            (one.points.cmp(&two.points), WinMethod::Points),
+            (one.advantages.cmp(&two.advantages), WinMethod::Advantages),
+            (two.penalties.cmp(&one.penalties), WinMethod::Penalties),
+        ];
+
+        tiebreaks.into_iter().find_map(|(ordering, method)| match ordering {
+            Ordering::Greater => Some((CompetitorNumber::One, method)),
+            Ordering::Less => Some((CompetitorNumber::Two, method)),
+            Ordering::Equal => None
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum RulesetKind {
+    #[default]
+    Ibjjf,
+    NoEscalation
+}
+
+impl RulesetKind {
+    pub fn rules(&self) -> &'static dyn Ruleset {
+        match self {
+            RulesetKind::Ibjjf => &IbjjfRuleset,
+            RulesetKind::NoEscalation => &NoEscalationRuleset
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            RulesetKind::Ibjjf => "IBJJF",
+            RulesetKind::NoEscalation => "No Penalty Escalation"
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum OvertimeMode {
+    #[default]
+    None,
+    GoldenScore,
+    ExtraPeriod,
+    Ebi
+}
+
+impl OvertimeMode {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            OvertimeMode::None => "None",
+            OvertimeMode::GoldenScore => "Golden Score",
+            OvertimeMode::ExtraPeriod => "Extra Period",
+            OvertimeMode::Ebi => "EBI Rounds"
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Overtime {
+    pub mode: OvertimeMode,
+    pub period_minutes: usize,
+    pub max_periods: usize
+}
+
+impl Default for Overtime {
+    fn default() -> Self {
+        Self {
+            mode: OvertimeMode::None,
+            period_minutes: 2,
+            max_periods: 1
+        }
+    }
+}
+
+impl Overtime {
+    pub fn allows_period(&self, period: usize) -> bool {
+        self.mode != OvertimeMode::None && period <= self.max_periods
+    }
+
+    pub fn get_period_name(&self, period: usize) -> String {
+        match self.mode {
+            OvertimeMode::GoldenScore => "Golden Score".to_owned(),
+            OvertimeMode::Ebi => format!("EBI Round {}", period),
+            _ if self.max_periods > 1 => format!("Overtime {}", period),
+            _ => "Overtime".to_owned()
+        }
+    }
+}
+
+pub struct IbjjfRuleset;
+
+impl IbjjfRuleset {
+    const ADVANTAGE_PENALTY: usize = 2;
+    const POINTS_PENALTY: usize = 3;
+    const DISQUALIFICATION_PENALTY: usize = 4;
+
+    fn escalate(penalised: &PlayerScore, opponent: &mut PlayerScore) {
+        if penalised.penalties >= IbjjfRuleset::ADVANTAGE_PENALTY {
+            opponent.advantages += 1;
+        }
+        if penalised.penalties >= IbjjfRuleset::POINTS_PENALTY {
+            opponent.points += 2;
+        }
+    }
+}
+
+impl Ruleset for IbjjfRuleset {
+    fn derive_score(&self, raw: &MatchScore) -> MatchScore {
+        let mut derived = raw.clone();
+        IbjjfRuleset::escalate(&raw.competitor_one_score, &mut derived.competitor_two_score);
+        IbjjfRuleset::escalate(&raw.competitor_two_score, &mut derived.competitor_one_score);
+        derived
+    }
+
+    fn disqualified(&self, raw: &MatchScore) -> Option<CompetitorNumber> {
+        if raw.competitor_one_score.penalties >= IbjjfRuleset::DISQUALIFICATION_PENALTY {
+            Some(CompetitorNumber::One)
+        } else if raw.competitor_two_score.penalties >= IbjjfRuleset::DISQUALIFICATION_PENALTY {
+            Some(CompetitorNumber::Two)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct NoEscalationRuleset;
+
+impl Ruleset for NoEscalationRuleset {
+    fn derive_score(&self, raw: &MatchScore) -> MatchScore {
+        raw.clone()
+    }
+
+    fn disqualified(&self, _raw: &MatchScore) -> Option<CompetitorNumber> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bjj_match::BJJMatch;
+    use crate::bjj_match::competitor::Competitor;
+    use crate::bjj_match::match_result::WinMethod;
+
+    fn penalised(penalties: usize) -> MatchScore {
+        let mut raw = MatchScore::default();
+        raw.competitor_one_score.penalties = penalties;
+        raw
+    }
+
+    fn penalised_match(penalties: usize) -> BJJMatch {
+        let mut bjj_match = BJJMatch::new(Competitor::default(), Competitor::default(), 5, 1, 1, RulesetKind::Ibjjf);
+        for _ in 0..penalties {
+            bjj_match.add_penalty(CompetitorNumber::One);
+        }
+        bjj_match
+    }
+
+    fn opponent_score(bjj_match: &BJJMatch) -> (usize, usize) {
+        let score = bjj_match.get_score().competitor_two_score;
+        (score.advantages, score.points)
+    }
+
+    #[test]
+    fn escalates_penalties_at_each_threshold() {
+        let expected = [(0, 0, 0), (1, 0, 0), (2, 1, 0), (3, 1, 2)];
+        for (penalties, advantages, points) in expected {
+            let derived = IbjjfRuleset.derive_score(&penalised(penalties)).competitor_two_score;
+            assert_eq!((derived.advantages, derived.points), (advantages, points), "{} penalties", penalties);
+            assert_eq!(IbjjfRuleset.disqualified(&penalised(penalties)), None);
+        }
+        assert_eq!(IbjjfRuleset.disqualified(&penalised(4)), Some(CompetitorNumber::One));
+        let unescalated = NoEscalationRuleset.derive_score(&penalised(3)).competitor_two_score;
+        assert_eq!((unescalated.advantages, unescalated.points), (0, 0));
+        assert_eq!(NoEscalationRuleset.disqualified(&penalised(4)), None);
+    }
+
+    #[test]
+    fn subtracting_penalties_rolls_escalation_back() {
+        let mut bjj_match = penalised_match(3);
+        assert_eq!(opponent_score(&bjj_match), (1, 2));
+
+        bjj_match.subtract_penalty(CompetitorNumber::One);
+        assert_eq!(opponent_score(&bjj_match), (1, 0));
+
+        bjj_match.subtract_penalty(CompetitorNumber::One);
+        assert_eq!(opponent_score(&bjj_match), (0, 0));
+    }
+
+    #[test]
+    fn fourth_penalty_disqualifies_until_it_is_removed() {
+        let mut bjj_match = penalised_match(4);
+        let result = bjj_match.result.as_ref().unwrap();
+        assert_eq!((result.winner, result.method), (CompetitorNumber::Two, WinMethod::Disqualification));
+
+        bjj_match.add_points(2, CompetitorNumber::One);
+        assert_eq!(bjj_match.get_score().competitor_one_score.points, 0);
+
+        bjj_match.subtract_penalty(CompetitorNumber::One);
+        assert!(bjj_match.result.is_none());
+        assert_eq!(opponent_score(&bjj_match), (1, 2));
+    }
+
+    #[test]
+    fn undoing_the_fourth_penalty_lifts_the_disqualification() {
+        let mut bjj_match = penalised_match(4);
+        bjj_match.undo();
+        assert!(bjj_match.result.is_none());
+        assert_eq!(bjj_match.get_raw_score().competitor_one_score.penalties, 3);
+        assert_eq!(opponent_score(&bjj_match), (1, 2));
+
+        bjj_match.redo();
+        assert_eq!(bjj_match.result.as_ref().map(|result| result.method), Some(WinMethod::Disqualification));
+    }
+
+    fn expired_match(score: impl Fn(&mut BJJMatch)) -> BJJMatch {
+        let mut bjj_match = BJJMatch::new(Competitor::default(), Competitor::default(), 5, 1, 1, RulesetKind::Ibjjf);
+        score(&mut bjj_match);
+        bjj_match.expire();
+        bjj_match
+    }
+
+    fn decision(bjj_match: &BJJMatch) -> Option<(CompetitorNumber, WinMethod)> {
+        bjj_match.result.as_ref().map(|result| (result.winner, result.method))
+    }
+
+    #[test]
+    fn decides_on_points_first() {
+        let bjj_match = expired_match(|bjj_match| {
+            bjj_match.add_points(2, CompetitorNumber::One);
+            bjj_match.add_points(3, CompetitorNumber::Two);
+            bjj_match.add_advantage(CompetitorNumber::One);
+        });
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::Two, WinMethod::Points)));
+    }
+
+    #[test]
+    fn decides_on_advantages_when_points_are_level() {
+        let bjj_match = expired_match(|bjj_match| {
+            bjj_match.add_points(2, CompetitorNumber::One);
+            bjj_match.add_points(2, CompetitorNumber::Two);
+            bjj_match.add_advantage(CompetitorNumber::One);
+            bjj_match.add_penalty(CompetitorNumber::One);
+        });
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::One, WinMethod::Advantages)));
+    }
+
+    #[test]
+    fn decides_on_fewer_penalties_when_advantages_are_level() {
+        let bjj_match = expired_match(|bjj_match| {
+            bjj_match.add_advantage(CompetitorNumber::One);
+            bjj_match.add_advantage(CompetitorNumber::Two);
+            bjj_match.add_penalty(CompetitorNumber::Two);
+        });
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::One, WinMethod::Penalties)));
+    }
+
+    #[test]
+    fn escalated_penalties_count_before_the_penalty_tiebreak() {
+        let bjj_match = expired_match(|bjj_match| {
+            bjj_match.add_penalty(CompetitorNumber::Two);
+            bjj_match.add_penalty(CompetitorNumber::Two);
+        });
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::One, WinMethod::Advantages)));
+    }
+
+    #[test]
+    fn requires_a_referee_decision_when_fully_tied() {
+        let mut bjj_match = expired_match(|bjj_match| {
+            bjj_match.add_points(2, CompetitorNumber::One);
+            bjj_match.add_points(2, CompetitorNumber::Two);
+            bjj_match.add_penalty(CompetitorNumber::One);
+            bjj_match.add_penalty(CompetitorNumber::Two);
+        });
+        assert_eq!(decision(&bjj_match), None);
+        assert!(bjj_match.referee_decision_required);
+        assert!(bjj_match.is_frozen());
+
+        bjj_match.add_points(2, CompetitorNumber::One);
+        bjj_match.record_referee_decision(CompetitorNumber::Two);
+        assert_eq!(decision(&bjj_match), Some((CompetitorNumber::Two, WinMethod::RefereeDecision)));
+        assert_eq!(bjj_match.get_score().competitor_one_score.points, 2);
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use strum_macros::EnumIter;
+use serde::{Deserialize, Serialize};
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::match_time::{Clock, MatchTime};
+
+#[derive(Debug, Copy, Clone, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum StoppageKind {
+    Injury,
+    Blood,
+    Doctor
+}
+
+impl StoppageKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            StoppageKind::Injury => "injury",
+            StoppageKind::Blood => "blood",
+            StoppageKind::Doctor => "doctor"
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            StoppageKind::Injury => "Injury",
+            StoppageKind::Blood => "Blood",
+            StoppageKind::Doctor => "Doctor"
+        }
+    }
+
+    pub fn get_allowance_millis(&self) -> usize {
+        match self {
+            StoppageKind::Injury => 2 * 60 * 1000,
+            StoppageKind::Blood => 4 * 60 * 1000,
+            StoppageKind::Doctor => 2 * 60 * 1000
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoppageTimer {
+    pub competitor: CompetitorNumber,
+    pub kind: StoppageKind,
+    pub time: MatchTime
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct StoppageTimers {
+    pub timers: Vec<StoppageTimer>
+}
+
+impl StoppageTimers {
+    pub fn get(&self, competitor: CompetitorNumber, kind: StoppageKind) -> Option<&StoppageTimer> {
+        self.timers.iter().find(|timer| timer.competitor == competitor && timer.kind == kind)
+    }
+
+    pub fn get_or_insert(&mut self, competitor: CompetitorNumber, kind: StoppageKind, clock: Arc<dyn Clock>) -> &mut StoppageTimer {
+        let index = match self.timers.iter().position(|timer| timer.competitor == competitor && timer.kind == kind) {
+            Some(index) => index,
+            None => {
+                self.timers.push(StoppageTimer {
+                    competitor,
+                    kind,
+                    time: MatchTime::with_clock(kind.get_allowance_millis(), clock)
+                });
+                self.timers.len() - 1
+            }
+        };
+        &mut self.timers[index]
+    }
+
+    pub fn get_remaining_millis(&self, competitor: CompetitorNumber, kind: StoppageKind) -> usize {
+        match self.get(competitor, kind) {
+            Some(timer) => timer.time.get_remaining_time_milliseconds(),
+            None => kind.get_allowance_millis()
+        }
+    }
+
+    pub fn is_running(&self, competitor: CompetitorNumber, kind: StoppageKind) -> bool {
+        self.get(competitor, kind).is_some_and(|timer| timer.time.running)
+    }
+
+    pub fn running(&self) -> impl Iterator<Item = &StoppageTimer> {
+        self.timers.iter().filter(|timer| timer.time.running)
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use crate::bjj_match::match_information::MatchInformation;
+use crate::persistence;
+use crate::textures;
+
+const EVENT_FILE: &str = "event.json";
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum EmblemMode {
+    #[default]
+    Flag,
+    TeamLogo
+}
+
+impl EmblemMode {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            EmblemMode::Flag => "Country Flag",
+            EmblemMode::TeamLogo => "Team Logo"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventSettings {
+    pub emblem: EmblemMode,
+    pub logos: Vec<String>,
+    pub logo_rotation_seconds: f64,
+    pub heading_template: String,
+    pub subheading_template: String,
+}
+
+impl Default for EventSettings {
+    fn default() -> Self {
+        Self {
+            emblem: EmblemMode::default(),
+            logos: Vec::new(),
+            logo_rotation_seconds: 10.0,
+            heading_template: "Mat {mat} · Fight {fight}".to_owned(),
+            subheading_template: "{age} {gender} {belt} {weight} · {round}".to_owned(),
+        }
+    }
+}
+
+impl EventSettings {
+    pub fn load() -> EventSettings {
+        persistence::load_config(EVENT_FILE)
+    }
+
+    pub fn get_heading(&self, info: &MatchInformation) -> String {
+        info.render_template(&self.heading_template)
+    }
+
+    pub fn get_subheading(&self, info: &MatchInformation) -> String {
+        info.render_template(&self.subheading_template)
+    }
+
+    pub fn get_current_logo_path(&self, time_seconds: f64) -> Option<PathBuf> {
+        let logos: Vec<&String> = self.logos.iter().filter(|logo| !logo.trim().is_empty()).collect();
+        if logos.is_empty() {
+            return None;
+        }
+
+        let index = match self.logo_rotation_seconds > 0.0 {
+            true => (time_seconds / self.logo_rotation_seconds) as usize % logos.len(),
+            false => 0
+        };
+        textures::resolve_logo_path(logos[index])
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        persistence::save_config(EVENT_FILE, self)
+    }
+}
@@ -0,0 +1,319 @@
+use strum_macros::EnumIter;
+use serde::{Deserialize, Serialize};
+
+pub struct CountryData {
+    pub country: Country,
+    pub code: &'static str,
+    pub alpha3: &'static str,
+    pub name: &'static str,
+    pub bytes: &'static [u8],
+}
+
+macro_rules! countries {
+    ($($variant:ident => $code:literal, $alpha3:literal, $name:literal, $file:literal;)*) => {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, EnumIter, Serialize, Deserialize)]
+        pub enum Country {
+            $($variant,)*
+        }
+
+        pub const COUNTRIES: &[CountryData] = &[
+            $(CountryData {
+                country: Country::$variant,
+                code: $code,
+                alpha3: $alpha3,
+                name: $name,
+                bytes: include_bytes!(concat!("../../assets/flags/", $file)),
+            },)*
+        ];
+    };
+}
+
+countries! {
+    Andorra => "AD", "AND", "Andorra", "ad.svg";
+    UnitedArabEmirates => "AE", "ARE", "United Arab Emirates", "ae.svg";
+    Afghanistan => "AF", "AFG", "Afghanistan", "af.svg";
+    Antigua => "AG", "ATG", "Antigua and Barbuda", "ag.svg";
+    Anguilla => "AI", "AIA", "Anguilla", "ai.svg";
+    Albania => "AL", "ALB", "Albania", "al.svg";
+    Armenia => "AM", "ARM", "Armenia", "am.svg";
+    Angola => "AO", "AGO", "Angola", "ao.svg";
+    Antarctica => "AQ", "ATA", "Antarctica", "aq.svg";
+    Argentina => "AR", "ARG", "Argentina", "ar.svg";
+    AmericanSamoa => "AS", "ASM", "American Samoa", "as.svg";
+    Austria => "AT", "AUT", "Austria", "at.svg";
+    Australia => "AU", "AUS", "Australia", "au.svg";
+    Aruba => "AW", "ABW", "Aruba", "aw.svg";
+    AlandIslands => "AX", "ALA", "Åland Islands", "ax.svg";
+    Azerbaijan => "AZ", "AZE", "Azerbaijan", "az.svg";
+    BosniaHerzegovina => "BA", "BIH", "Bosnia and Herzegovina", "ba.svg";
+    Barbados => "BB", "BRB", "Barbados", "bb.svg";
+    Bangladesh => "BD", "BGD", "Bangladesh", "bd.svg";
+    Belgium => "BE", "BEL", "Belgium", "be.svg";
+    BurkinaFaso => "BF", "BFA", "Burkina Faso", "bf.svg";
+    Bulgaria => "BG", "BGR", "Bulgaria", "bg.svg";
+    Bahrain => "BH", "BHR", "Bahrain", "bh.svg";
+    Burundi => "BI", "BDI", "Burundi", "bi.svg";
+    Benin => "BJ", "BEN", "Benin", "bj.svg";
+    SaintBarthelemy => "BL", "BLM", "Saint Barthélemy", "bl.svg";
+    Bermuda => "BM", "BMU", "Bermuda", "bm.svg";
+    BruneiDarussalam => "BN", "BRN", "Brunei Darussalam", "bn.svg";
+    Bolivia => "BO", "BOL", "Bolivia", "bo.svg";
+    Bonaire => "BQ", "BES", "Bonaire, Sint Eustatius and Saba", "bq.svg";
+    Brazil => "BR", "BRA", "Brazil", "br.svg";
+    Bahamas => "BS", "BHS", "Bahamas", "bs.svg";
+    Bhutan => "BT", "BTN", "Bhutan", "bt.svg";
+    BouvetIsland => "BV", "BVT", "Bouvet Island", "bv.svg";
+    Botswana => "BW", "BWA", "Botswana", "bw.svg";
+    Belarus => "BY", "BLR", "Belarus", "by.svg";
+    Belize => "BZ", "BLZ", "Belize", "bz.svg";
+    Canada => "CA", "CAN", "Canada", "ca.svg";
+    CocosIslands => "CC", "CCK", "Cocos (Keeling) Islands", "cc.svg";
+    CongoDemocraticRepublic => "CD", "COD", "Democratic Republic of the Congo", "cd.svg";
+    CentralAfricanRepublic => "CF", "CAF", "Central African Republic", "cf.svg";
+    Congo => "CG", "COG", "Congo", "cg.svg";
+    Switzerland => "CH", "CHE", "Switzerland", "ch.svg";
+    CotedIvoire => "CI", "CIV", "Côte d'Ivoire", "ci.svg";
+    CookIslands => "CK", "COK", "Cook Islands", "ck.svg";
+    Chile => "CL", "CHL", "Chile", "cl.svg";
+    Cameroon => "CM", "CMR", "Cameroon", "cm.svg";
+    China => "CN", "CHN", "China", "cn.svg";
+    Colombia => "CO", "COL", "Colombia", "co.svg";
+    CostaRica => "CR", "CRI", "Costa Rica", "cr.svg";
+    Cuba => "CU", "CUB", "Cuba", "cu.svg";
+    CaboVerde => "CV", "CPV", "Cabo Verde", "cv.svg";
+    Curacao => "CW", "CUW", "Curaçao", "cw.svg";
+    ChristmasIsland => "CX", "CXR", "Christmas Island", "cx.svg";
+    Cyprus => "CY", "CYP", "Cyprus", "cy.svg";
+    Czechia => "CZ", "CZE", "Czechia", "cz.svg";
+    Germany => "DE", "DEU", "Germany", "de.svg";
+    Djibouti => "DJ", "DJI", "Djibouti", "dj.svg";
+    Denmark => "DK", "DNK", "Denmark", "dk.svg";
+    Dominica => "DM", "DMA", "Dominica", "dm.svg";
+    DominicanRepublic => "DO", "DOM", "Dominican Republic", "do.svg";
+    Algeria => "DZ", "DZA", "Algeria", "dz.svg";
+    Ecuador => "EC", "ECU", "Ecuador", "ec.svg";
+    Estonia => "EE", "EST", "Estonia", "ee.svg";
+    Egypt => "EG", "EGY", "Egypt", "eg.svg";
+    WesternSahara => "EH", "ESH", "Western Sahara", "eh.svg";
+    Eritrea => "ER", "ERI", "Eritrea", "er.svg";
+    Spain => "ES", "ESP", "Spain", "es.svg";
+    Ethiopia => "ET", "ETH", "Ethiopia", "et.svg";
+    EuropeanUnion => "EU", "EUU", "European Union", "eu.svg";
+    Finland => "FI", "FIN", "Finland", "fi.svg";
+    Fiji => "FJ", "FJI", "Fiji", "fj.svg";
+    FalklandIslands => "FK", "FLK", "Falkland Islands", "fk.svg";
+    Micronesia => "FM", "FSM", "Micronesia", "fm.svg";
+    FaroeIslands => "FO", "FRO", "Faroe Islands", "fo.svg";
+    France => "FR", "FRA", "France", "fr.svg";
+    Gabon => "GA", "GAB", "Gabon", "ga.svg";
+    UnitedKingdom => "GB", "GBR", "United Kingdom", "gb.svg";
+    England => "GB-ENG", "ENG", "England", "gb-eng.svg";
+    NorthernIreland => "GB-NIR", "NIR", "Northern Ireland", "gb-nir.svg";
+    Scotland => "GB-SCT", "SCT", "Scotland", "gb-sct.svg";
+    Wales => "GB-WLS", "WLS", "Wales", "gb-wls.svg";
+    Grenada => "GD", "GRD", "Grenada", "gd.svg";
+    Georgia => "GE", "GEO", "Georgia", "ge.svg";
+    FrenchGuiana => "GF", "GUF", "French Guiana", "gf.svg";
+    Guernsey => "GG", "GGY", "Guernsey", "gg.svg";
+    Ghana => "GH", "GHA", "Ghana", "gh.svg";
+    Gibraltar => "GI", "GIB", "Gibraltar", "gi.svg";
+    Greenland => "GL", "GRL", "Greenland", "gl.svg";
+    Gambia => "GM", "GMB", "Gambia", "gm.svg";
+    Guinea => "GN", "GIN", "Guinea", "gn.svg";
+    Guadeloupe => "GP", "GLP", "Guadeloupe", "gp.svg";
+    EquatorialGuinea => "GQ", "GNQ", "Equatorial Guinea", "gq.svg";
+    Greece => "GR", "GRC", "Greece", "gr.svg";
+    SouthGeorgia => "GS", "SGS", "South Georgia and the South Sandwich Islands", "gs.svg";
+    Guatemala => "GT", "GTM", "Guatemala", "gt.svg";
+    Guam => "GU", "GUM", "Guam", "gu.svg";
+    GuineaBissau => "GW", "GNB", "Guinea-Bissau", "gw.svg";
+    Guyana => "GY", "GUY", "Guyana", "gy.svg";
+    HongKong => "HK", "HKG", "Hong Kong", "hk.svg";
+    HeardIsland => "HM", "HMD", "Heard Island and McDonald Islands", "hm.svg";
+    Honduras => "HN", "HND", "Honduras", "hn.svg";
+    Croatia => "HR", "HRV", "Croatia", "hr.svg";
+    Haiti => "HT", "HTI", "Haiti", "ht.svg";
+    Hungary => "HU", "HUN", "Hungary", "hu.svg";
+    Indonesia => "ID", "IDN", "Indonesia", "id.svg";
+    Ireland => "IE", "IRL", "Ireland", "ie.svg";
+    Israel => "IL", "ISR", "Israel", "il.svg";
+    IsleOfMan => "IM", "IMN", "Isle of Man", "im.svg";
+    India => "IN", "IND", "India", "in.svg";
+    BritishIndianOceanTerritory => "IO", "IOT", "British Indian Ocean Territory", "io.svg";
+    Iraq => "IQ", "IRQ", "Iraq", "iq.svg";
+    Iran => "IR", "IRN", "Iran", "ir.svg";
+    Iceland => "IS", "ISL", "Iceland", "is.svg";
+    Italy => "IT", "ITA", "Italy", "it.svg";
+    Jersey => "JE", "JEY", "Jersey", "je.svg";
+    Jamaica => "JM", "JAM", "Jamaica", "jm.svg";
+    Jordan => "JO", "JOR", "Jordan", "jo.svg";
+    Japan => "JP", "JPN", "Japan", "jp.svg";
+    Kenya => "KE", "KEN", "Kenya", "ke.svg";
+    Kyrgyzstan => "KG", "KGZ", "Kyrgyzstan", "kg.svg";
+    Cambodia => "KH", "KHM", "Cambodia", "kh.svg";
+    Kiribati => "KI", "KIR", "Kiribati", "ki.svg";
+    Comoros => "KM", "COM", "Comoros", "km.svg";
+    SaintKittsNevis => "KN", "KNA", "Saint Kitts and Nevis", "kn.svg";
+    KoreaDemocraticPeoplesRepublic => "KP", "PRK", "North Korea", "kp.svg";
+    KoreaRepublic => "KR", "KOR", "South Korea", "kr.svg";
+    Kuwait => "KW", "KWT", "Kuwait", "kw.svg";
+    CaymanIslands => "KY", "CYM", "Cayman Islands", "ky.svg";
+    Kazakhstan => "KZ", "KAZ", "Kazakhstan", "kz.svg";
+    Lao => "LA", "LAO", "Laos", "la.svg";
+    Lebanon => "LB", "LBN", "Lebanon", "lb.svg";
+    SaintLucia => "LC", "LCA", "Saint Lucia", "lc.svg";
+    Liechtenstein => "LI", "LIE", "Liechtenstein", "li.svg";
+    SriLanka => "LK", "LKA", "Sri Lanka", "lk.svg";
+    Liberia => "LR", "LBR", "Liberia", "lr.svg";
+    Lesotho => "LS", "LSO", "Lesotho", "ls.svg";
+    Lithuania => "LT", "LTU", "Lithuania", "lt.svg";
+    Luxembourg => "LU", "LUX", "Luxembourg", "lu.svg";
+    Latvia => "LV", "LVA", "Latvia", "lv.svg";
+    Libya => "LY", "LBY", "Libya", "ly.svg";
+    Morocco => "MA", "MAR", "Morocco", "ma.svg";
+    Monaco => "MC", "MCO", "Monaco", "mc.svg";
+    Moldova => "MD", "MDA", "Moldova", "md.svg";
+    Montenegro => "ME", "MNE", "Montenegro", "me.svg";
+    SaintMartin => "MF", "MAF", "Saint Martin", "mf.svg";
+    Madagascar => "MG", "MDG", "Madagascar", "mg.svg";
+    MarshallIslands => "MH", "MHL", "Marshall Islands", "mh.svg";
+    NorthMacedonia => "MK", "MKD", "North Macedonia", "mk.svg";
+    Mali => "ML", "MLI", "Mali", "ml.svg";
+    Myanmar => "MM", "MMR", "Myanmar", "mm.svg";
+    Mongolia => "MN", "MNG", "Mongolia", "mn.svg";
+    Macao => "MO", "MAC", "Macao", "mo.svg";
+    NorthernMarianaIslands => "MP", "MNP", "Northern Mariana Islands", "mp.svg";
+    Martinique => "MQ", "MTQ", "Martinique", "mq.svg";
+    Mauritania => "MR", "MRT", "Mauritania", "mr.svg";
+    Montserrat => "MS", "MSR", "Montserrat", "ms.svg";
+    Malta => "MT", "MLT", "Malta", "mt.svg";
+    Mauritius => "MU", "MUS", "Mauritius", "mu.svg";
+    Maldives => "MV", "MDV", "Maldives", "mv.svg";
+    Malawi => "MW", "MWI", "Malawi", "mw.svg";
+    Mexico => "MX", "MEX", "Mexico", "mx.svg";
+    Malaysia => "MY", "MYS", "Malaysia", "my.svg";
+    Mozambique => "MZ", "MOZ", "Mozambique", "mz.svg";
+    Namibia => "NA", "NAM", "Namibia", "na.svg";
+    NewCaledonia => "NC", "NCL", "New Caledonia", "nc.svg";
+    Niger => "NE", "NER", "Niger", "ne.svg";
+    NorfolkIsland => "NF", "NFK", "Norfolk Island", "nf.svg";
+    Nigeria => "NG", "NGA", "Nigeria", "ng.svg";
+    Nicaragua => "NI", "NIC", "Nicaragua", "ni.svg";
+    Netherlands => "NL", "NLD", "Netherlands", "nl.svg";
+    Norway => "NO", "NOR", "Norway", "no.svg";
+    Nepal => "NP", "NPL", "Nepal", "np.svg";
+    Nauru => "NR", "NRU", "Nauru", "nr.svg";
+    Niue => "NU", "NIU", "Niue", "nu.svg";
+    NewZealand => "NZ", "NZL", "New Zealand", "nz.svg";
+    Oman => "OM", "OMN", "Oman", "om.svg";
+    Panama => "PA", "PAN", "Panama", "pa.svg";
+    Peru => "PE", "PER", "Peru", "pe.svg";
+    FrenchPolynesia => "PF", "PYF", "French Polynesia", "pf.svg";
+    PapuaNewGuinea => "PG", "PNG", "Papua New Guinea", "pg.svg";
+    Philippines => "PH", "PHL", "Philippines", "ph.svg";
+    Pakistan => "PK", "PAK", "Pakistan", "pk.svg";
+    Poland => "PL", "POL", "Poland", "pl.svg";
+    SaintPierreandMiquelon => "PM", "SPM", "Saint Pierre and Miquelon", "pm.svg";
+    Pitcairn => "PN", "PCN", "Pitcairn", "pn.svg";
+    PuertoRico => "PR", "PRI", "Puerto Rico", "pr.svg";
+    Palestine => "PS", "PSE", "Palestine", "ps.svg";
+    Portugal => "PT", "PRT", "Portugal", "pt.svg";
+    Palau => "PW", "PLW", "Palau", "pw.svg";
+    Paraguay => "PY", "PRY", "Paraguay", "py.svg";
+    Qatar => "QA", "QAT", "Qatar", "qa.svg";
+    Reunion => "RE", "REU", "Réunion", "re.svg";
+    Romania => "RO", "ROU", "Romania", "ro.svg";
+    Serbia => "RS", "SRB", "Serbia", "rs.svg";
+    RussianFederation => "RU", "RUS", "Russia", "ru.svg";
+    Rwanda => "RW", "RWA", "Rwanda", "rw.svg";
+    SaudiArabia => "SA", "SAU", "Saudi Arabia", "sa.svg";
+    SolomonIslands => "SB", "SLB", "Solomon Islands", "sb.svg";
+    Seychelles => "SC", "SYC", "Seychelles", "sc.svg";
+    Sudan => "SD", "SDN", "Sudan", "sd.svg";
+    Sweden => "SE", "SWE", "Sweden", "se.svg";
+    Singapore => "SG", "SGP", "Singapore", "sg.svg";
+    SaintHelena => "SH", "SHN", "Saint Helena", "sh.svg";
+    Slovenia => "SI", "SVN", "Slovenia", "si.svg";
+    SvalbardandJanMayen => "SJ", "SJM", "Svalbard and Jan Mayen", "sj.svg";
+    Slovakia => "SK", "SVK", "Slovakia", "sk.svg";
+    SierraLeone => "SL", "SLE", "Sierra Leone", "sl.svg";
+    SanMarino => "SM", "SMR", "San Marino", "sm.svg";
+    Senegal => "SN", "SEN", "Senegal", "sn.svg";
+    Somalia => "SO", "SOM", "Somalia", "so.svg";
+    Suriname => "SR", "SUR", "Suriname", "sr.svg";
+    SouthSudan => "SS", "SSD", "South Sudan", "ss.svg";
+    SaoTomeandPrincipe => "ST", "STP", "São Tomé and Príncipe", "st.svg";
+    ElSalvador => "SV", "SLV", "El Salvador", "sv.svg";
+    SintMaarten => "SX", "SXM", "Sint Maarten", "sx.svg";
+    SyrianArabRepublic => "SY", "SYR", "Syria", "sy.svg";
+    Eswatini => "SZ", "SWZ", "Eswatini", "sz.svg";
+    TurksandCaicosIslands => "TC", "TCA", "Turks and Caicos Islands", "tc.svg";
+    Chad => "TD", "TCD", "Chad", "td.svg";
+    FrenchSouthernTerritories => "TF", "ATF", "French Southern Territories", "tf.svg";
+    Togo => "TG", "TGO", "Togo", "tg.svg";
+    Thailand => "TH", "THA", "Thailand", "th.svg";
+    Tajikistan => "TJ", "TJK", "Tajikistan", "tj.svg";
+    Tokelau => "TK", "TKL", "Tokelau", "tk.svg";
+    TimorLeste => "TL", "TLS", "Timor-Leste", "tl.svg";
+    Turkmenistan => "TM", "TKM", "Turkmenistan", "tm.svg";
+    Tunisia => "TN", "TUN", "Tunisia", "tn.svg";
+    Tonga => "TO", "TON", "Tonga", "to.svg";
+    Turkiye => "TR", "TUR", "Türkiye", "tr.svg";
+    TrinidadTobago => "TT", "TTO", "Trinidad and Tobago", "tt.svg";
+    Tuvalu => "TV", "TUV", "Tuvalu", "tv.svg";
+    Taiwan => "TW", "TWN", "Taiwan", "tw.svg";
+    Tanzania => "TZ", "TZA", "Tanzania", "tz.svg";
+    Ukraine => "UA", "UKR", "Ukraine", "ua.svg";
+    Uganda => "UG", "UGA", "Uganda", "ug.svg";
+    UnitedStatesMinorOutlyingIslands => "UM", "UMI", "United States Minor Outlying Islands", "um.svg";
+    UnitedStates => "US", "USA", "United States", "us.svg";
+    Uruguay => "UY", "URY", "Uruguay", "uy.svg";
+    Uzbekistan => "UZ", "UZB", "Uzbekistan", "uz.svg";
+    HolySee => "VA", "VAT", "Holy See", "va.svg";
+    SaintVincentGrenadines => "VC", "VCT", "Saint Vincent and the Grenadines", "vc.svg";
+    Venezuela => "VE", "VEN", "Venezuela", "ve.svg";
+    VirginIslandsBritish => "VG", "VGB", "British Virgin Islands", "vg.svg";
+    VirginIslands => "VI", "VIR", "U.S. Virgin Islands", "vi.svg";
+    VietNam => "VN", "VNM", "Vietnam", "vn.svg";
+    Vanuatu => "VU", "VUT", "Vanuatu", "vu.svg";
+    WallisandFutuna => "WF", "WLF", "Wallis and Futuna", "wf.svg";
+    Samoa => "WS", "WSM", "Samoa", "ws.svg";
+    Kosovo => "XK", "XKX", "Kosovo", "xk.svg";
+    Yemen => "YE", "YEM", "Yemen", "ye.svg";
+    Mayotte => "YT", "MYT", "Mayotte", "yt.svg";
+    SouthAfrica => "ZA", "ZAF", "South Africa", "za.svg";
+    Zambia => "ZM", "ZMB", "Zambia", "zm.svg";
+    Zimbabwe => "ZW", "ZWE", "Zimbabwe", "zw.svg";
+}
+
+pub const ALIASES: &[(&str, Country)] = &[
+    ("USA", Country::UnitedStates),
+    ("America", Country::UnitedStates),
+    ("UK", Country::UnitedKingdom),
+    ("Great Britain", Country::UnitedKingdom),
+    ("Britain", Country::UnitedKingdom),
+    ("UAE", Country::UnitedArabEmirates),
+    ("Emirates", Country::UnitedArabEmirates),
+    ("Brasil", Country::Brazil),
+    ("Holland", Country::Netherlands),
+    ("Korea", Country::KoreaRepublic),
+    ("Republic of Korea", Country::KoreaRepublic),
+    ("DPRK", Country::KoreaDemocraticPeoplesRepublic),
+    ("Russian Federation", Country::RussianFederation),
+    ("Czech Republic", Country::Czechia),
+    ("Turkey", Country::Turkiye),
+    ("Ivory Coast", Country::CotedIvoire),
+    ("Swaziland", Country::Eswatini),
+    ("Burma", Country::Myanmar),
+    ("Macedonia", Country::NorthMacedonia),
+    ("Vatican", Country::HolySee),
+    ("East Timor", Country::TimorLeste),
+    ("Cape Verde", Country::CaboVerde),
+    ("DRC", Country::CongoDemocraticRepublic),
+    ("Deutschland", Country::Germany),
+    ("Espana", Country::Spain),
+    ("Nippon", Country::Japan),
+    ("Persia", Country::Iran),
+    ("Kiwi", Country::NewZealand),
+    ("Aotearoa", Country::NewZealand),
+];
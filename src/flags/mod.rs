@@ -1,376 +1,66 @@
-use std::collections::BTreeMap;
-use eframe::egui;
-use eframe::egui::TextureOptions;
-use eframe::epaint::TextureHandle;
-use egui_extras::image::FitTo;
-use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
+mod countries;
+mod textures;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
-pub enum Country {
-    Andorra,
-    UnitedArabEmirates,
-    Afghanistan,
-    Antigua,
-    Anguilla,
-    Albania,
-    Armenia,
-    // Angola,
-    // Antarctica,
-    // Argentina,
-    // AmericanSamoa,
-    // Austria,
-    Australia,
-    // Aruba,
-    // AlandIslands,
-    // Azerbaijan,
-    // BosniaHerzegovina,
-    // Barbados,
-    // Bangladesh,
-    // Belgium,
-    // BurkinaFaso,
-    // Bulgaria,
-    // Bahrain,
-    // Burundi,
-    // Benin,
-    // SaintBarthelemy,
-    // Bermuda,
-    // BruneiDarussalam,
-    // Bolivia,
-    // Bonaire,
-    Brazil,
-    // Bahamas,
-    // Bhutan,
-    // BouvetIsland,
-    // Botswana,
-    // Belarus,
-    // Belize,
-    // Canada,
-    // CocosIslands,
-    // CongoDemocraticRepublic,
-    // CentralAfricanRepublic,
-    // Congo,
-    // Switzerland,
-    // CotedIvoire,
-    // CookIslands,
-    // Chile,
-    // Cameroon,
-    // China,
-    // Colombia,
-    // CostaRica,
-    // Cuba,
-    // CaboVerde,
-    // Curaçao,
-    // ChristmasIsland,
-    // Cyprus,
-    // Czechia,
-    // Germany,
-    // Djibouti,
-    // Denmark,
-    // Dominica,
-    // DominicanRepublic,
-    // Algeria,
-    // Ecuador,
-    // Estonia,
-    // Egypt,
-    // WesternSahara,
-    // Eritrea,
-    // Spain,
-    // Ethiopia,
-    // Finland,
-    // Fiji,
-    // FalklandIslands,
-    // Micronesia,
-    // FaroeIslands,
-    // France,
-    // Gabon,
-    // UnitedKingdom,
-    // Grenada,
-    // Georgia,
-    // FrenchGuiana,
-    // Guernsey,
-    // Ghana,
-    // Gibraltar,
-    // Greenland,
-    // Gambia,
-    // Guinea,
-    // Guadeloupe,
-    // EquatorialGuinea,
-    // Greece,
-    // SouthGeorgia,
-    // Guatemala,
-    // Guam,
-    // GuineaBissau,
-    // Guyana,
-    // HongKong,
-    // HeardIsland,
-    // Honduras,
-    // Croatia,
-    // Haiti,
-    // Hungary,
-    // Indonesia,
-    // Ireland,
-    // Israel,
-    // IsleOfMan,
-    // India,
-    // BritishIndianOceanTerritory,
-    // Iraq,
-    // Iran,
-    // Iceland,
-    // Italy,
-    // Jersey,
-    // Jamaica,
-    // Jordan,
-    // Japan,
-    // Kenya,
-    // Kyrgyzstan,
-    // Cambodia,
-    // Kiribati,
-    // Comoros,
-    // SaintKittsNevis,
-    // KoreaDemocraticPeoplesRepublic,
-    // KoreaRepublic,
-    // Kuwait,
-    // CaymanIslands,
-    // Kazakhstan,
-    // Lao,
-    // Lebanon,
-    // SaintLucia,
-    // Liechtenstein,
-    // SriLanka,
-    // Liberia,
-    // Lesotho,
-    // Lithuania,
-    // Luxembourg,
-    // Latvia,
-    // Libya,
-    // Morocco,
-    // Monaco,
-    // Moldova,
-    // Montenegro,
-    // SaintMartin,
-    // Madagascar,
-    // MarshallIslands,
-    // NorthMacedonia,
-    // Mali,
-    // Myanmar,
-    // Mongolia,
-    // Macao,
-    // NorthernMarianaIslands,
-    // Martinique,
-    // Mauritania,
-    // Montserrat,
-    // Malta,
-    // Mauritius,
-    // Maldives,
-    // Malawi,
-    // Mexico,
-    // Malaysia,
-    // Mozambique,
-    // Namibia,
-    // NewCaledonia,
-    // Niger,
-    // NorfolkIsland,
-    // Nigeria,
-    // Nicaragua,
-    // Netherlands,
-    // Norway,
-    // Nepal,
-    // Nauru,
-    // Niue,
-    // NewZealand,
-    // Oman,
-    // Panama,
-    // Peru,
-    // FrenchPolynesia,
-    // PapuaNewGuinea,
-    // Philippines,
-    // Pakistan,
-    // Poland,
-    // SaintPierreandMiquelon,
-    // Pitcairn,
-    // PuertoRico,
-    // Palestine,
-    // Portugal,
-    // Palau,
-    // Paraguay,
-    // Qatar,
-    // Réunion,
-    // Romania,
-    // Serbia,
-    // RussianFederation,
-    // Rwanda,
-    // SaudiArabia,
-    // SolomonIslands,
-    // Seychelles,
-    // Sudan,
-    // Sweden,
-    // Singapore,
-    // SaintHelena,
-    // Slovenia,
-    // SvalbardandJanMayen,
-    // Slovakia,
-    // SierraLeone,
-    // SanMarino,
-    // Senegal,
-    // Somalia,
-    // Suriname,
-    // SouthSudan,
-    // SaoTomeandPrincipe,
-    // ElSalvador,
-    // SintMaarten,
-    // SyrianArabRepublic,
-    // Eswatini,
-    // TurksandCaicosIslands,
-    // Chad,
-    // FrenchSouthernTerritories,
-    // Togo,
-    // Thailand,
-    // Tajikistan,
-    // Tokelau,
-    // TimorLeste,
-    // Turkmenistan,
-    // Tunisia,
-    // Tonga,
-    // Turkiye,
-    // TrinidadTobago,
-    // Tuvalu,
-    // Taiwan,
-    // Tanzania,
-    // Ukraine,
-    // Uganda,
-    // UnitedStatesMinorOutlyingIslands,
-    UnitedStates,
-    // Uruguay,
-    // Uzbekistan,
-    // HolySee,
-    // SaintVincentGrenadines,
-    // Venezuela,
-    // VirginIslandsBritish,
-    // VirginIslands,
-    // VietNam,
-    // Vanuatu,
-    // WallisandFutuna,
-    // Samoa,
-    // Yemen,
-    // Mayotte,
-    // SouthAfrica,
-    // Zambia,
-    // Zimbabwe,
-}
+pub use countries::{Country, CountryData, ALIASES, COUNTRIES};
+pub use textures::{FlagTextures, SCOREBOARD_FLAG_HEIGHT, THUMBNAIL_FLAG_HEIGHT};
 
 impl Country {
-    fn flag(&self) -> Flag {
-        match self {
-            Country::Andorra => Flag {
-                code: "AD".to_owned(),
-                name: "Andorra".to_owned(),
-                country: Country::Andorra,
-                bytes: include_bytes!("../../assets/flags/as.svg"),
-                handle: None
-            },
-            Country::UnitedArabEmirates => Flag {
-                code: "AE".to_owned(),
-                name: "United Arab Emirates".to_owned(),
-                country: Country::UnitedArabEmirates,
-                bytes: include_bytes!("../../assets/flags/ae.svg"),
-                handle: None
-            },
-            Country::Afghanistan => Flag {
-                code: "AF".to_owned(),
-                name: "Afghanistan".to_owned(),
-                country: Country::Afghanistan,
-                bytes: include_bytes!("../../assets/flags/af.svg"),
-                handle: None
-            },
-            Country::Antigua => Flag {
-                code: "AG".to_owned(),
-                name: "Antigua and Barbuda".to_owned(),
-                country: Country::Antigua,
-                bytes: include_bytes!("../../assets/flags/ag.svg"),
-                handle: None
-            },
-            Country::Anguilla => Flag {
-                code: "AI".to_owned(),
-                name: "Anguilla".to_owned(),
-                country: Country::Anguilla,
-                bytes: include_bytes!("../../assets/flags/ai.svg"),
-                handle: None
-            },
-            Country::Albania => Flag {
-                code: "AL".to_owned(),
-                name: "Albania".to_owned(),
-                country: Country::Albania,
-                bytes: include_bytes!("../../assets/flags/al.svg"),
-                handle: None
-            },
-            Country::Armenia => Flag {
-                code: "AM".to_owned(),
-                name: "Armenia".to_owned(),
-                country: Country::Armenia,
-                bytes: include_bytes!("../../assets/flags/am.svg"),
-                handle: None
-            },
-            Country::Australia => Flag {
-                code: "AU".to_owned(),
-                name: "Australia".to_owned(),
-                country: Country::Australia,
-                bytes: include_bytes!("../../assets/flags/au.svg"),
-                handle: None
-            },
-            Country::Brazil => Flag {
-                code: "BR".to_owned(),
-                name: "Brazil".to_owned(),
-                country: Country::Brazil,
-                bytes: include_bytes!("../../assets/flags/br.svg"),
-                handle: None
-            },
-            Country::UnitedStates => Flag {
-                code: "US".to_owned(),
-                name: "United States".to_owned(),
-                country: Country::UnitedStates,
-                bytes: include_bytes!("../../assets/flags/us.svg"),
-                handle: None
-            }
-        }
+    pub fn from_code(code: &str) -> Option<Country> {
+        COUNTRIES.iter()
+            .find(|data| data.code.eq_ignore_ascii_case(code))
+            .map(|data| data.country)
     }
-}
 
-pub struct Flag {
-    pub code: String,
-    pub name: String,
-    pub country: Country,
-    bytes: &'static [u8],
-    pub handle: Option<TextureHandle>
-}
+    pub fn get_code(&self) -> &'static str {
+        self.data().code
+    }
+
+    pub fn get_svg_bytes(&self) -> &'static [u8] {
+        self.data().bytes
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.data().name
+    }
+
+    pub fn get_alpha3(&self) -> &'static str {
+        self.data().alpha3
+    }
+
+    pub fn search(query: &str) -> Vec<Country> {
+        let query = query.trim().to_lowercase();
+        let mut results: Vec<(usize, Country)> = COUNTRIES.iter()
+            .filter_map(|data| data.country.match_rank(&query).map(|rank| (rank, data.country)))
+            .collect();
 
-impl Flag {
-    pub fn load_textures(ctx: &egui::Context) -> BTreeMap<Country, Flag>{
-        let mut map = BTreeMap::new();
-        for country in Country::iter() {
-            let mut flag = country.flag();
+        results.sort_by(|(rank_a, a), (rank_b, b)| rank_a.cmp(rank_b).then(a.get_name().cmp(b.get_name())));
+        results.into_iter().map(|(_, country)| country).collect()
+    }
 
-            let image = egui_extras::image::load_svg_bytes_with_size(
-                flag.bytes,
-                FitTo::Height(360)
-            );
+    fn match_rank(&self, query: &str) -> Option<usize> {
+        if query.is_empty() {
+            return Some(0);
+        }
 
-            match image {
-                Ok(color_image) => {
-                    let texture = ctx.load_texture(
-                        flag.code.as_str(),
-                        color_image,
-                        TextureOptions::default()
-                    );
-                    flag.handle = Some(texture);
-                    map.insert(country, flag);
-                }
-                Err(e) => {
-                    println!("Error loading SVG: {}", e);
-                }
-            }
+        let name = self.get_name().to_lowercase();
+        let aliases: Vec<String> = ALIASES.iter()
+            .filter(|(_, country)| country == self)
+            .map(|(alias, _)| alias.to_lowercase())
+            .collect();
 
+        if self.get_code().eq_ignore_ascii_case(query) || self.get_alpha3().eq_ignore_ascii_case(query)
+            || name == query || aliases.iter().any(|alias| alias == query) {
+            Some(0)
+        } else if name.starts_with(query) || aliases.iter().any(|alias| alias.starts_with(query)) {
+            Some(1)
+        } else if name.contains(query) || aliases.iter().any(|alias| alias.contains(query)) {
+            Some(2)
+        } else {
+            None
         }
-        map
+    }
+
+    fn data(&self) -> &'static CountryData {
+        &COUNTRIES[*self as usize]
     }
 }
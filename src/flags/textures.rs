@@ -0,0 +1,58 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use eframe::egui::{self, ColorImage, TextureHandle};
+use crate::flags::Country;
+use crate::textures::{self, TextureLoader};
+
+pub const SCOREBOARD_FLAG_HEIGHT: u32 = 360;
+pub const THUMBNAIL_FLAG_HEIGHT: u32 = 32;
+
+pub struct FlagTextures {
+    loader: TextureLoader<(Country, u32)>,
+}
+
+impl Default for FlagTextures {
+    fn default() -> Self {
+        Self {
+            loader: TextureLoader::new("flag"),
+        }
+    }
+}
+
+impl FlagTextures {
+    pub fn start(&mut self, ctx: &egui::Context) {
+        let cache_dir = dirs::cache_dir().map(|dir| dir.join("bjj_scoreboard").join("flags"));
+        self.loader.start(ctx, move |(country, height)| load_flag(cache_dir.as_deref(), *country, *height));
+    }
+
+    pub fn get(&mut self, country: Country, height: u32) -> Option<TextureHandle> {
+        self.loader.get(&(country, height))
+    }
+}
+
+fn load_flag(cache_dir: Option<&Path>, country: Country, height: u32) -> anyhow::Result<ColorImage> {
+    let cache_path = cache_dir.map(|dir| dir.join(cache_file_name(country, height)));
+
+    if let Some(path) = &cache_path {
+        if let Ok(cached) = textures::load_image_file(path, height) {
+            return Ok(cached);
+        }
+    }
+
+    let image = textures::load_svg(country.get_svg_bytes(), height)?;
+
+    if let Some(path) = cache_path {
+        if let Err(e) = textures::save_png(&path, &image) {
+            println!("Error caching flag: {:#}", e);
+        }
+    }
+
+    Ok(image)
+}
+
+fn cache_file_name(country: Country, height: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    country.get_svg_bytes().hash(&mut hasher);
+    format!("{}-{:016x}-{}.png", country.get_code().to_lowercase(), hasher.finish(), height)
+}
@@ -0,0 +1,205 @@
+use std::fs;
+use std::sync::OnceLock;
+use ab_glyph::Font;
+use eframe::egui::{self, FontData, FontDefinitions, FontFamily};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use crate::persistence;
+
+const FONTS_FILE: &str = "fonts.json";
+pub const MAIN_FONT: &[u8] = include_bytes!("../../assets/fonts/BebasNeue-Regular.ttf");
+const FALLBACK_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const FALLBACK_CANDIDATES: [&str; 12] = [
+    "/usr/share/fonts/truetype/noto/NotoSans-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansArabic-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoNaskhArabic-Regular.ttf",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+    "/Library/Fonts/Arial Unicode.ttf",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "C:\\Windows\\Fonts\\arial.ttf",
+    "C:\\Windows\\Fonts\\msgothic.ttc",
+    "C:\\Windows\\Fonts\\tahoma.ttf",
+];
+
+const CJK_SAMPLES: [char; 3] = ['漢', 'あ', '한'];
+
+static SYSTEM_FALLBACKS: OnceLock<Vec<Vec<u8>>> = OnceLock::new();
+
+#[derive(Debug, Copy, Clone, PartialEq, EnumIter)]
+pub enum FontRole {
+    Names,
+    Numbers,
+    Clock
+}
+
+impl FontRole {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            FontRole::Names => "Names",
+            FontRole::Numbers => "Numbers",
+            FontRole::Clock => "Clock"
+        }
+    }
+
+    pub fn family(&self) -> FontFamily {
+        FontFamily::Name(self.font_name().into())
+    }
+
+    fn font_name(&self) -> &'static str {
+        match self {
+            FontRole::Names => "names",
+            FontRole::Numbers => "numbers",
+            FontRole::Clock => "clock"
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FontChoice {
+    pub path: String,
+    pub y_offset: f32,
+}
+
+impl Default for FontChoice {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            y_offset: 0.08,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FontSettings {
+    pub names: FontChoice,
+    pub numbers: FontChoice,
+    pub clock: FontChoice,
+    pub fallbacks: Vec<String>,
+}
+
+impl FontSettings {
+    pub fn load() -> FontSettings {
+        persistence::load_config(FONTS_FILE)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        persistence::save_config(FONTS_FILE, self)
+    }
+
+    pub fn get_choice_mut(&mut self, role: FontRole) -> &mut FontChoice {
+        match role {
+            FontRole::Names => &mut self.names,
+            FontRole::Numbers => &mut self.numbers,
+            FontRole::Clock => &mut self.clock
+        }
+    }
+
+    pub fn definitions(&self) -> FontDefinitions {
+        let mut fonts = FontDefinitions::default();
+        let defaults = fonts.families.get(&FontFamily::Proportional).cloned().unwrap_or_default();
+
+        let user_fallbacks = self.fallbacks.iter()
+            .filter_map(|path| read_font(path))
+            .map(FontData::from_owned);
+        let system_fallbacks = SYSTEM_FALLBACKS.get_or_init(read_system_fallbacks).iter()
+            .map(|bytes| FontData::from_static(bytes));
+        let all_fallbacks = user_fallbacks
+            .chain(std::iter::once(FontData::from_static(FALLBACK_FONT)))
+            .chain(system_fallbacks);
+
+        let mut fallbacks = Vec::new();
+        for (index, font_data) in all_fallbacks.enumerate() {
+            let name = format!("fallback_{}", index);
+            fonts.font_data.insert(name.clone(), font_data);
+            fallbacks.push(name);
+        }
+
+        for (role, choice) in [(FontRole::Names, &self.names), (FontRole::Numbers, &self.numbers), (FontRole::Clock, &self.clock)] {
+            let mut font_data = read_font(&choice.path)
+                .map(FontData::from_owned)
+                .unwrap_or_else(|| FontData::from_static(MAIN_FONT));
+            font_data.tweak.y_offset_factor = choice.y_offset;
+            fonts.font_data.insert(role.font_name().to_owned(), font_data);
+
+            let mut family = vec![role.font_name().to_owned()];
+            family.extend(defaults.iter().cloned());
+            family.extend(fallbacks.iter().cloned());
+            fonts.families.insert(role.family(), family);
+        }
+
+        let proportional = fonts.families.entry(FontFamily::Proportional).or_default();
+        proportional.insert(0, FontRole::Names.font_name().to_owned());
+        proportional.extend(fallbacks.iter().cloned());
+
+        let monospace = fonts.families.entry(FontFamily::Monospace).or_default();
+        monospace.push(FontRole::Names.font_name().to_owned());
+        monospace.extend(fallbacks.iter().cloned());
+
+        fonts
+    }
+
+    pub fn apply(&self, ctx: &egui::Context) {
+        ctx.set_fonts(self.definitions());
+    }
+
+    pub fn font_bytes(&self, role: FontRole) -> Vec<u8> {
+        let choice = match role {
+            FontRole::Names => &self.names,
+            FontRole::Numbers => &self.numbers,
+            FontRole::Clock => &self.clock
+        };
+        read_font(&choice.path).unwrap_or_else(|| MAIN_FONT.to_vec())
+    }
+
+    pub fn covers_cjk(&self) -> bool {
+        let user_fallbacks: Vec<Vec<u8>> = self.fallbacks.iter()
+            .filter_map(|path| fs::read(path.trim()).ok())
+            .collect();
+
+        user_fallbacks.iter()
+            .chain(SYSTEM_FALLBACKS.get_or_init(read_system_fallbacks).iter())
+            .any(|bytes| covers(bytes, &CJK_SAMPLES))
+    }
+}
+
+fn read_system_fallbacks() -> Vec<Vec<u8>> {
+    FALLBACK_CANDIDATES.into_iter()
+        .filter(|path| fs::metadata(path).is_ok())
+        .filter_map(read_font)
+        .collect()
+}
+
+fn covers(bytes: &[u8], samples: &[char]) -> bool {
+    match ab_glyph::FontRef::try_from_slice(bytes) {
+        Ok(font) => samples.iter().all(|sample| font.glyph_id(*sample).0 != 0),
+        Err(_) => false
+    }
+}
+
+fn read_font(path: &str) -> Option<Vec<u8>> {
+    let path = path.trim();
+    if path.is_empty() {
+        return None;
+    }
+
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            println!("Error reading font {}: {}", path, e);
+            return None;
+        }
+    };
+
+    match ab_glyph::FontRef::try_from_slice(&bytes) {
+        Ok(_) => Some(bytes),
+        Err(e) => {
+            println!("Error reading font {}: {}", path, e);
+            None
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use eframe::egui::{Pos2, Rect};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
 
 #[derive(Debug)]
 pub struct MatchGrid {
@@ -109,20 +111,116 @@ impl Split for Rect {
     }
 }
 
-pub fn calc_grids(rect: Rect) -> MatchGrid {
-    let (top,bottom) = rect.split_v(SplitMode::Proportional, 0.75);
-    let (top, middle) = top.split_v(SplitMode::Proportional, 0.5);
+pub trait Layout {
+    fn calc_grids(&self, rect: Rect) -> MatchGrid;
 
-    let competitor_one = calc_competitor_grid(top);
-    let competitor_two = calc_competitor_grid(middle);
+    fn text_scale(&self) -> f32 {
+        1.0
+    }
 
-    let time = calc_time_grid(bottom);
+    fn background(&self, grid: &MatchGrid) -> Rect {
+        grid.full
+    }
+}
 
-    MatchGrid {
-        full: rect,
-        competitor_one,
-        competitor_two,
-        time
+#[derive(Debug, Copy, Clone, PartialEq, Default, EnumIter, Serialize, Deserialize)]
+pub enum LayoutKind {
+    #[default]
+    Stacked,
+    SideBySide,
+    BottomBar
+}
+
+impl LayoutKind {
+    pub fn layout(&self) -> &'static dyn Layout {
+        match self {
+            LayoutKind::Stacked => &StackedLayout,
+            LayoutKind::SideBySide => &SideBySideLayout,
+            LayoutKind::BottomBar => &BottomBarLayout
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            LayoutKind::Stacked => "Stacked",
+            LayoutKind::SideBySide => "Side by Side",
+            LayoutKind::BottomBar => "Bottom Bar"
+        }
+    }
+}
+
+pub struct StackedLayout;
+
+impl Layout for StackedLayout {
+    fn calc_grids(&self, rect: Rect) -> MatchGrid {
+        let (top,bottom) = rect.split_v(SplitMode::Proportional, 0.75);
+        let (top, middle) = top.split_v(SplitMode::Proportional, 0.5);
+
+        let competitor_one = calc_competitor_grid(top);
+        let competitor_two = calc_competitor_grid(middle);
+
+        let time = calc_time_grid(bottom);
+
+        MatchGrid {
+            full: rect,
+            competitor_one,
+            competitor_two,
+            time
+        }
+    }
+}
+
+pub struct SideBySideLayout;
+
+impl Layout for SideBySideLayout {
+    fn calc_grids(&self, rect: Rect) -> MatchGrid {
+        let (top, bottom) = rect.split_v(SplitMode::Proportional, 0.75);
+        let (left, right) = top.split_h(SplitMode::Proportional, 0.5);
+
+        MatchGrid {
+            full: rect,
+            competitor_one: calc_column_competitor_grid(left),
+            competitor_two: calc_column_competitor_grid(right),
+            time: calc_time_grid(bottom)
+        }
+    }
+
+    fn text_scale(&self) -> f32 {
+        0.7
+    }
+}
+
+pub struct BottomBarLayout;
+
+impl Layout for BottomBarLayout {
+    fn calc_grids(&self, rect: Rect) -> MatchGrid {
+        let (_, bar) = rect.split_v(SplitMode::Proportional, 0.78);
+        let (rest, logo) = bar.split_h(SplitMode::Exact, bar.width() - bar.height());
+        let (competitors, clock) = rest.split_h(SplitMode::Proportional, 0.75);
+        let (top, bottom) = competitors.split_v(SplitMode::Proportional, 0.5);
+        let (time, fight_info) = clock.split_v(SplitMode::Proportional, 0.6);
+        let (fight_info_heading, fight_info_sub_heading) = fight_info.split_v(SplitMode::Proportional, 0.5);
+
+        MatchGrid {
+            full: rect,
+            competitor_one: calc_competitor_grid(top),
+            competitor_two: calc_competitor_grid(bottom),
+            time: TimeGrid {
+                full: bar,
+                time,
+                fight_info_heading,
+                fight_info_sub_heading,
+                logo
+            }
+        }
+    }
+
+    fn text_scale(&self) -> f32 {
+        0.4
+    }
+
+    fn background(&self, grid: &MatchGrid) -> Rect {
+        grid.time.full
     }
 }
 
@@ -159,4 +257,26 @@ fn calc_competitor_grid(rect: Rect) -> CompetitorGrid {
         advantages,
         penalties
     }
-}
\ No newline at end of file
+}
+
+fn calc_column_competitor_grid(rect: Rect) -> CompetitorGrid {
+    let (left, main) = rect.split_v(SplitMode::Proportional, 0.3);
+    let (comp, team) = left.split_v(SplitMode::Proportional, 2.0 / 3.0);
+    let (flag, name) = comp.split_h(SplitMode::Proportional, 1.0 / 4.0);
+    let (points, right) = main.split_v(SplitMode::Proportional, 0.75);
+    let (advantages, penalties) = right.split_h(SplitMode::Proportional, 0.5);
+
+    CompetitorGrid {
+        full: rect,
+        main,
+        left,
+        right,
+        comp,
+        flag,
+        name,
+        team,
+        points,
+        advantages,
+        penalties
+    }
+}
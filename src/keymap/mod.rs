@@ -0,0 +1,290 @@
+use std::fmt;
+use std::fs;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use crate::action::Action;
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::ebi::{EbiOutcome, EbiPosition};
+use crate::persistence;
+
+const KEYMAP_FILE: &str = "keymap.json";
+const NAMED_KEYS: [&str; 17] = [
+    "Down", "Left", "Right", "Up", "Escape", "Tab", "Backspace", "Enter", "Space", "Insert",
+    "Delete", "Home", "End", "PageUp", "PageDown", "Minus", "Plus",
+];
+const SHIFTED_KEYS: [&str; 2] = ["Minus", "Plus"];
+const SETUP_KEY: &str = "M";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KeyChord {
+    pub key: String,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: &str) -> KeyChord {
+        KeyChord { key: key.to_owned(), ctrl: false, shift: false, alt: false }
+    }
+
+    pub fn with_modifiers(key: &str, ctrl: bool, shift: bool, alt: bool) -> KeyChord {
+        KeyChord {
+            key: key.to_owned(),
+            ctrl,
+            shift: shift && !SHIFTED_KEYS.contains(&key),
+            alt,
+        }
+    }
+
+    pub fn from_egui(key: egui::Key, modifiers: egui::Modifiers) -> KeyChord {
+        KeyChord::with_modifiers(key.name(), modifiers.command, modifiers.shift, modifiers.alt)
+    }
+
+    pub fn is_reserved(&self) -> bool {
+        self.key == SETUP_KEY && !self.ctrl && !self.alt
+    }
+
+    pub fn parse(text: &str) -> Result<KeyChord, String> {
+        let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let key = parts.pop().filter(|key| !key.is_empty()).ok_or_else(|| format!("\"{}\" has no key", text))?;
+        let key = normalize_key_name(key).ok_or_else(|| format!("\"{}\" is not a known key", key))?;
+
+        let (mut ctrl, mut shift, mut alt) = (false, false, false);
+        for modifier in parts {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" | "command" => ctrl = true,
+                "shift" => shift = true,
+                "alt" | "option" => alt = true,
+                _ => return Err(format!("\"{}\" is not a known modifier", modifier))
+            }
+        }
+        Ok(KeyChord::with_modifiers(&key, ctrl, shift, alt))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+fn normalize_key_name(name: &str) -> Option<String> {
+    if let Some(named) = NAMED_KEYS.iter().find(|named| named.eq_ignore_ascii_case(name)) {
+        return Some((*named).to_owned());
+    }
+
+    let upper = name.to_ascii_uppercase();
+    let is_character = upper.len() == 1 && upper.chars().all(|c| c.is_ascii_alphanumeric());
+    let is_function = upper.strip_prefix('F')
+        .and_then(|number| number.parse::<usize>().ok())
+        .is_some_and(|number| (1..=20).contains(&number));
+
+    match is_character || is_function {
+        true => Some(upper),
+        false => None
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeymapEntry {
+    keys: String,
+    action: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeymapFile {
+    bindings: Vec<KeymapEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    pub bindings: Vec<(KeyChord, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let one = CompetitorNumber::One;
+        let two = CompetitorNumber::Two;
+        let bindings = [
+            ("Ctrl+Z", Action::Undo),
+            ("Ctrl+Y", Action::Redo),
+            ("Ctrl+Shift+Z", Action::Redo),
+            ("F11", Action::ToggleFullscreen),
+            ("Q", Action::AddPoints(one, 2)),
+            ("W", Action::AddPoints(one, 3)),
+            ("E", Action::AddPoints(one, 4)),
+            ("R", Action::AddAdvantage(one)),
+            ("T", Action::AddPenalty(one)),
+            ("Y", Action::SubtractPoint(one)),
+            ("U", Action::SubtractAdvantage(one)),
+            ("I", Action::SubtractPenalty(one)),
+            ("A", Action::AddPoints(two, 2)),
+            ("S", Action::AddPoints(two, 3)),
+            ("D", Action::AddPoints(two, 4)),
+            ("F", Action::AddAdvantage(two)),
+            ("G", Action::AddPenalty(two)),
+            ("H", Action::SubtractPoint(two)),
+            ("J", Action::SubtractAdvantage(two)),
+            ("K", Action::SubtractPenalty(two)),
+            ("C", Action::RecordResult),
+            ("Space", Action::StartStop),
+            ("Minus", Action::AdjustClock(-1)),
+            ("Plus", Action::AdjustClock(1)),
+            ("O", Action::SetEbiPosition(EbiPosition::BackControl)),
+            ("P", Action::SetEbiPosition(EbiPosition::SpiderWeb)),
+            ("V", Action::RecordEbiOutcome(EbiOutcome::Submission)),
+            ("B", Action::RecordEbiOutcome(EbiOutcome::Escape)),
+        ];
+
+        Self {
+            bindings: bindings.iter()
+                .filter_map(|(keys, action)| KeyChord::parse(keys).ok().map(|chord| (chord, *action)))
+                .collect()
+        }
+    }
+}
+
+impl Keymap {
+    pub fn load() -> (Keymap, Vec<String>) {
+        let Some(path) = persistence::config_dir().map(|dir| dir.join(KEYMAP_FILE)) else {
+            return (Keymap::default(), Vec::new());
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return (Keymap::default(), Vec::new());
+        };
+
+        let (keymap, problems) = match Keymap::parse(&contents) {
+            Ok(keymap) => {
+                let warnings = keymap.validate();
+                (keymap, warnings)
+            },
+            Err(mut errors) => {
+                errors.push(format!("{} was not loaded, using the default keys", path.display()));
+                (Keymap::default(), errors)
+            }
+        };
+
+        for problem in &problems {
+            println!("Keymap: {}", problem);
+        }
+        (keymap, problems)
+    }
+
+    pub fn parse(contents: &str) -> Result<Keymap, Vec<String>> {
+        let file: KeymapFile = serde_json::from_str(contents).map_err(|e| vec![e.to_string()])?;
+        let mut keymap = Keymap { bindings: Vec::new() };
+        let mut errors = Vec::new();
+
+        for entry in file.bindings {
+            let chord = match KeyChord::parse(&entry.keys) {
+                Ok(chord) => chord,
+                Err(e) => {
+                    errors.push(e);
+                    continue;
+                }
+            };
+            let Some(action) = Action::from_name(&entry.action) else {
+                errors.push(format!("\"{}\" is not a known action", entry.action));
+                continue;
+            };
+
+            match keymap.action_for(&chord) {
+                Some(existing) if existing == action => {},
+                Some(existing) => errors.push(format!("{} is bound to both {} and {}", chord, existing.name(), action.name())),
+                None => keymap.bindings.push((chord, action))
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(keymap),
+            false => Err(errors)
+        }
+    }
+
+    pub fn validate(&self) -> Vec<String> {
+        let mut missing: Vec<Action> = Vec::new();
+        for (_, action) in Keymap::default().bindings {
+            if self.chords_for(action).is_empty() && !missing.contains(&action) {
+                missing.push(action);
+            }
+        }
+
+        let reserved = self.bindings.iter()
+            .filter(|(chord, _)| chord.is_reserved())
+            .map(|(chord, action)| format!("{} is reserved for match setup, {} will not work in the terminal", chord, action.name()));
+
+        missing.iter()
+            .map(|action| format!("No key is bound to {}", action.name()))
+            .chain(reserved)
+            .collect()
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let file = KeymapFile {
+            bindings: self.bindings.iter()
+                .map(|(chord, action)| KeymapEntry { keys: chord.to_string(), action: action.name() })
+                .collect()
+        };
+        persistence::save_config(KEYMAP_FILE, &file)
+    }
+
+    pub fn action_for(&self, chord: &KeyChord) -> Option<Action> {
+        self.bindings.iter()
+            .find(|(bound, _)| bound == chord)
+            .map(|(_, action)| *action)
+    }
+
+    pub fn chords_for(&self, action: Action) -> Vec<&KeyChord> {
+        self.bindings.iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(chord, _)| chord)
+            .collect()
+    }
+
+    pub fn bind(&mut self, chord: KeyChord, action: Action) -> Option<Action> {
+        let replaced = self.action_for(&chord).filter(|existing| *existing != action);
+        self.bindings.retain(|(bound, _)| *bound != chord);
+        self.bindings.push((chord, action));
+        replaced
+    }
+
+    pub fn unbind(&mut self, action: Action) {
+        self.bindings.retain(|(_, bound)| *bound != action);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_shift_except_on_symbol_keys() {
+        let shift = egui::Modifiers::SHIFT;
+        assert_eq!(KeyChord::from_egui(egui::Key::Q, shift), KeyChord::parse("Shift+Q").unwrap());
+        assert_eq!(KeyChord::from_egui(egui::Key::PlusEquals, shift), KeyChord::parse("Plus").unwrap());
+        assert_eq!(KeyChord::parse("Shift+Minus").unwrap(), KeyChord::new("Minus"));
+    }
+
+    #[test]
+    fn reports_reserved_setup_key() {
+        let mut keymap = Keymap::default();
+        keymap.bind(KeyChord::parse("Shift+M").unwrap(), Action::StartStop);
+        assert!(keymap.validate().iter().any(|problem| problem.contains("reserved")));
+        assert!(!KeyChord::parse("Ctrl+M").unwrap().is_reserved());
+    }
+    #[test]
+    fn default_keys_do_not_record_results() {
+        let keymap = Keymap::default();
+        assert!(keymap.bindings.iter().all(|(_, action)| !matches!(action, Action::Win(..))));
+    }
+}
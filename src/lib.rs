@@ -1,25 +1,56 @@
 pub mod flags;
+pub mod fonts;
 pub mod ui;
 pub mod audio;
 pub mod grid;
 pub mod bjj_match;
+pub mod persistence;
+pub mod action;
+pub mod textures;
+pub mod teams;
+pub mod event;
+pub mod keymap;
+pub mod tui;
+#[cfg(feature = "http-api")]
+pub mod server;
 
-use std::collections::BTreeMap;
 use std::ops::Add;
 use eframe::egui::{self, Align2, Color32, Key, Pos2, Rect, Rounding, Vec2};
+use strum::IntoEnumIterator;
 
-use crate::flags::{Flag, Country};
+use crate::action::Action;
+use crate::flags::{FlagTextures, SCOREBOARD_FLAG_HEIGHT};
+use crate::fonts::{FontRole, FontSettings};
 use crate::audio::Audio;
 use crate::bjj_match::BJJMatch;
 use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::division::{Belt, Gender};
 use crate::bjj_match::match_information::MatchInformation;
-use crate::grid::{calc_grids, RectReduce};
+use crate::bjj_match::match_result::WinMethod;
+use crate::bjj_match::ruleset::{OvertimeMode, RulesetKind};
+use crate::bjj_match::stoppage::StoppageKind;
+use crate::bjj_match::ebi::{EbiOutcome, EbiPosition};
+use crate::bjj_match::match_time::MatchTime;
+use crate::grid::{LayoutKind, RectReduce};
+use crate::teams::{Team, TeamRegistry};
+use crate::textures::{LogoTextures, LOGO_HEIGHT};
+use crate::event::{EmblemMode, EventSettings};
+use crate::keymap::{KeyChord, Keymap};
+use crate::persistence::MatchStore;
+#[cfg(feature = "http-api")]
+use crate::server::ApiServer;
 use crate::ui::{ColorScheme, FontSizes};
+use crate::ui::theme::{Theme, ThemeSettings};
+use crate::ui::country_picker::country_picker;
+
+const LOCKED_SETTING_HINT: &str = "Can't be changed once the match has started";
 
 pub enum AppState {
     NewMatchDialog,
     InProgress,
     Ready,
+    AwaitingDecision,
+    Finished,
 }
 
 pub struct BjjScoreboard {
@@ -27,11 +58,35 @@ pub struct BjjScoreboard {
     fullscreen: bool,
     app_state: AppState,
     match_dialog_open: bool,
+    audience_open: bool,
+    result_dialog_open: bool,
+    teams_dialog_open: bool,
+    event_dialog_open: bool,
+    keymap_dialog_open: bool,
+    theme_dialog_open: bool,
+    result_winner: CompetitorNumber,
+    result_method: WinMethod,
     first_run: bool,
     color_scheme: ColorScheme,
     font_sizes: FontSizes,
-    flags: BTreeMap<Country, Flag>,
+    layout: LayoutKind,
+    themes: ThemeSettings,
+    fonts: FontSettings,
+    fonts_cover_cjk: bool,
+    theme_name: String,
+    flags: FlagTextures,
+    teams: TeamRegistry,
+    logos: LogoTextures,
+    event: EventSettings,
+    keymap: Keymap,
+    keymap_problems: Vec<String>,
+    keymap_capture: Option<Action>,
     audio: Audio,
+    match_store: MatchStore,
+    interrupted_match: Option<BJJMatch>,
+    remaining_time_input: String,
+    #[cfg(feature = "http-api")]
+    api_server: Option<ApiServer>,
 }
 
 impl Default for BjjScoreboard {
@@ -41,320 +96,1226 @@ impl Default for BjjScoreboard {
             fullscreen: false,
             app_state: AppState::NewMatchDialog,
             match_dialog_open: true,
+            audience_open: true,
+            result_dialog_open: false,
+            teams_dialog_open: false,
+            event_dialog_open: false,
+            keymap_dialog_open: false,
+            theme_dialog_open: false,
+            result_winner: CompetitorNumber::One,
+            result_method: WinMethod::Submission,
             first_run: true,
             color_scheme: Default::default(),
             font_sizes: Default::default(),
-            flags: BTreeMap::new(),
-            audio: Default::default()
+            layout: Default::default(),
+            themes: Default::default(),
+            fonts: Default::default(),
+            fonts_cover_cjk: true,
+            theme_name: String::new(),
+            flags: Default::default(),
+            teams: Default::default(),
+            logos: Default::default(),
+            event: Default::default(),
+            keymap: Default::default(),
+            keymap_problems: Vec::new(),
+            keymap_capture: None,
+            audio: Default::default(),
+            match_store: Default::default(),
+            interrupted_match: None,
+            remaining_time_input: String::new(),
+            #[cfg(feature = "http-api")]
+            api_server: None
         }
     }
 }
 
 impl eframe::App for BjjScoreboard {
-    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         if self.first_run {
             self.setup(ctx);
             self.first_run = false;
+            ctx.request_repaint();
+            return;
+        }
+
+        #[cfg(feature = "http-api")]
+        self.process_api_actions();
+
+        self.autosave();
+
+        if let AppState::InProgress = self.app_state {
+            if self.bjj_match.time.running && self.bjj_match.time.get_remaining_time_milliseconds() == 0 {
+                self.audio.play_air_horn();
+                self.bjj_match.expire();
+            }
+        }
+
+        if let Some((competitor, _)) = self.bjj_match.expire_stoppages() {
+            self.audio.play_air_horn();
+            self.result_winner = competitor.opponent();
+            self.result_method = WinMethod::Injury;
+            self.result_dialog_open = true;
+        }
+
+        if self.bjj_match.is_finished() {
+            self.app_state = AppState::Finished;
+            self.result_dialog_open = false;
+        } else if self.bjj_match.referee_decision_required {
+            self.app_state = AppState::AwaitingDecision;
+            self.result_dialog_open = false;
+        } else if let AppState::Finished | AppState::AwaitingDecision = self.app_state {
+            self.app_state = match self.bjj_match.time.started {
+                true => AppState::InProgress,
+                false => AppState::Ready
+            };
         }
 
+        self.draw_operator_console(ctx);
+
         match self.app_state {
             AppState::NewMatchDialog => {
-                self.draw_new_match_modal(ctx)
-            },
-            AppState::InProgress => {
-                if self.bjj_match.time.get_remaining_time_milliseconds() == 0 {
-                    self.audio.play_air_horn();
-                    self.app_state = AppState::Ready;
-                    self.draw_new_match_modal(ctx);
-                    ctx.request_repaint();
-                    return;
+                if self.interrupted_match.is_some() {
+                    self.draw_restore_modal(ctx);
                 }
-                self.draw_active_match_screen(ctx);
-                self.handle_input(ctx, frame);
-                ctx.request_repaint();
             },
-            AppState::Ready => {
-                self.draw_active_match_screen(ctx);
-                self.handle_input(ctx, frame);
-                ctx.request_repaint();
-            }
+            AppState::InProgress | AppState::Ready => {
+                self.draw_result_modal(ctx);
+            },
+            AppState::AwaitingDecision => {
+                self.draw_referee_decision_modal(ctx);
+            },
+            AppState::Finished => {}
+        }
+
+        if self.interrupted_match.is_none() {
+            self.draw_new_match_modal(ctx);
         }
+        self.draw_teams_modal(ctx);
+        self.draw_event_modal(ctx);
+        self.draw_keymap_modal(ctx);
+        self.draw_theme_modal(ctx);
+
+        if self.audience_open {
+            self.show_audience_display(ctx);
+        }
+
+        self.handle_input(ctx);
+        ctx.request_repaint();
     }
 }
 
 impl BjjScoreboard {
     fn setup(&mut self, ctx: &egui::Context) {
-        self.load_fonts(ctx);
-        self.flags = Flag::load_textures(ctx);
+        self.fonts = FontSettings::load();
+        self.themes = ThemeSettings::load();
+        self.apply_theme();
+        self.flags.start(ctx);
+        self.logos.start(ctx);
+        self.teams = TeamRegistry::load();
+        self.event = EventSettings::load();
+        (self.keymap, self.keymap_problems) = Keymap::load();
         self.audio.init();
-    }
 
-    fn load_fonts(&self, ctx: &egui::Context) {
-        let mut fonts = egui::FontDefinitions::default();
-
-        let mut font_data = egui::FontData::from_static(include_bytes!("../assets/fonts/BebasNeue-Regular.ttf"));
-        font_data.tweak.y_offset_factor = 0.08;
+        if let Some(saved) = self.match_store.load() {
+            if saved.time.started && !saved.is_finished() {
+                self.interrupted_match = Some(saved);
+            }
+        }
 
-        fonts.font_data.insert(
-            "main_font".to_owned(),
-            font_data,
-        );
+        #[cfg(feature = "http-api")]
+        match ApiServer::start(&ApiServer::address(), &ApiServer::live_address(), ctx.clone(), &self.color_scheme) {
+            Ok(server) => self.api_server = Some(server),
+            Err(e) => println!("Error starting API server: {:#}", e)
+        }
 
-        fonts
-            .families
-            .entry(egui::FontFamily::Proportional)
-            .or_default()
-            .insert(0, "main_font".to_owned());
+        self.apply_fonts(ctx);
+    }
 
-        fonts
-            .families
-            .entry(egui::FontFamily::Monospace)
-            .or_default()
-            .push("main_font".to_owned());
+    fn apply_fonts(&mut self, ctx: &egui::Context) {
+        self.fonts.apply(ctx);
+        self.fonts_cover_cjk = self.fonts.covers_cjk();
+        if !self.fonts_cover_cjk {
+            println!("No CJK font found, Chinese, Japanese and Korean names won't display until one is added as a fallback font");
+        }
 
-        ctx.set_fonts(fonts);
+        #[cfg(feature = "http-api")]
+        if let Some(server) = &self.api_server {
+            server.set_main_font(self.fonts.font_bytes(FontRole::Names));
+        }
     }
 
-    fn draw_active_match_screen(&mut self, ctx: &egui::Context) {
-        egui::CentralPanel::default().show(ctx, |ui| {
-            self.ui(ui);
-        });
-    }
+    #[cfg(feature = "http-api")]
+    fn process_api_actions(&mut self) {
+        let actions = match &self.api_server {
+            Some(server) => server.take_actions(),
+            None => return
+        };
 
-    fn handle_input(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
-        if ctx.input(|i| i.key_pressed(Key::F11)) {
-            self.fullscreen = !self.fullscreen;
-            frame.set_fullscreen(self.fullscreen);
-        }
-        if ctx.input(|i| i.key_pressed(Key::Q)) {
-            self.bjj_match.add_points(2, CompetitorNumber::One);
-        }
-        if ctx.input(|i| i.key_pressed(Key::W)) {
-            self.bjj_match.add_points(3, CompetitorNumber::One);
-        }
-        if ctx.input(|i| i.key_pressed(Key::E)) {
-            self.bjj_match.add_points(4, CompetitorNumber::One);
-        }
-        if ctx.input(|i| i.key_pressed(Key::R)) {
-            self.bjj_match.add_advantage( CompetitorNumber::One);
+        for action in actions {
+            self.apply_action(action);
         }
-        if ctx.input(|i| i.key_pressed(Key::T)) {
-            self.bjj_match.add_penalty( CompetitorNumber::One);
-        }
-        if ctx.input(|i| i.key_pressed(Key::Y)) {
-            self.bjj_match.subtract_point( CompetitorNumber::One);
+
+        if let Some(server) = &mut self.api_server {
+            server.publish(&self.bjj_match);
         }
-        if ctx.input(|i| i.key_pressed(Key::U)) {
-            self.bjj_match.subtract_advantage( CompetitorNumber::One);
+    }
+
+    fn autosave(&mut self) {
+        if self.interrupted_match.is_some() {
+            return;
         }
-        if ctx.input(|i| i.key_pressed(Key::I)) {
-            self.bjj_match.subtract_penalty( CompetitorNumber::One);
+
+        if let Err(e) = self.match_store.save(&self.bjj_match) {
+            println!("Error saving match: {:#}", e);
         }
-        if ctx.input(|i| i.key_pressed(Key::A)) {
-            self.bjj_match.add_points(2, CompetitorNumber::Two);
+    }
+
+    fn draw_restore_modal(&mut self, ctx: &egui::Context) {
+        let Some(interrupted) = &self.interrupted_match else {
+            return;
+        };
+        let mut restore = None;
+
+        egui::Window::new("Restore Interrupted Match")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(format!("{} vs {} was interrupted with {} remaining.",
+                    interrupted.info.competitor_one.get_display_name(),
+                    interrupted.info.competitor_two.get_display_name(),
+                    interrupted.time.get_remaining_time_string()));
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new("Restore Match")).clicked() {
+                        restore = Some(true);
+                    }
+                    if ui.add(egui::Button::new("Discard")).clicked() {
+                        restore = Some(false);
+                    }
+                });
+            });
+
+        match restore {
+            Some(true) => {
+                if let Some(interrupted) = self.interrupted_match.take() {
+                    self.bjj_match = interrupted;
+                    self.app_state = AppState::InProgress;
+                    self.match_dialog_open = false;
+                }
+            },
+            Some(false) => {
+                self.interrupted_match = None;
+                self.match_store.clear();
+            },
+            None => {}
         }
-        if ctx.input(|i| i.key_pressed(Key::S)) {
-            self.bjj_match.add_points(3, CompetitorNumber::Two);
+    }
+
+    fn show_audience_display(&mut self, ctx: &egui::Context) {
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("audience_display"),
+            egui::ViewportBuilder::default()
+                .with_title("BJJ Scoreboard")
+                .with_inner_size([1280.0, 720.0]),
+            |ctx, class| {
+                if class == egui::ViewportClass::Embedded {
+                    egui::Window::new("Audience Display")
+                        .default_size([640.0, 360.0])
+                        .show(ctx, |ui| {
+                            let (rect, _) = ui.allocate_exact_size(ui.available_size(), egui::Sense::hover());
+                            self.ui(&mut ui.child_ui(rect, *ui.layout()));
+                        });
+                    return;
+                }
+
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::none())
+                    .show(ctx, |ui| {
+                        self.ui(ui);
+                    });
+
+                if ctx.input(|i| i.viewport().close_requested()) {
+                    self.audience_open = false;
+                    self.fullscreen = false;
+                }
+                if ctx.input(|i| i.viewport().fullscreen) != Some(self.fullscreen) {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(self.fullscreen));
+                }
+
+                self.handle_input(ctx);
+            });
+    }
+
+    fn draw_operator_console(&mut self, ctx: &egui::Context) {
+        let mut actions = Vec::new();
+
+        egui::TopBottomPanel::top("operator_controls").show(ctx, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                let start_stop = match self.app_state {
+                    AppState::Ready => "Start Match",
+                    AppState::Finished => "Next Match",
+                    _ if self.bjj_match.time.running => "Stop Clock",
+                    _ => "Start Clock"
+                };
+                if ui.add_enabled(matches!(self.app_state, AppState::Ready | AppState::InProgress | AppState::Finished), egui::Button::new(start_stop)).clicked() {
+                    actions.push(Action::StartStop);
+                }
+                if ui.add_enabled(!self.bjj_match.is_frozen(), egui::Button::new("Record Result")).clicked() {
+                    actions.push(Action::RecordResult);
+                }
+                if ui.add_enabled(!self.bjj_match.log.events.is_empty(), egui::Button::new("Undo")).clicked() {
+                    actions.push(Action::Undo);
+                }
+                if ui.add_enabled(!self.bjj_match.log.undone.is_empty(), egui::Button::new("Redo")).clicked() {
+                    actions.push(Action::Redo);
+                }
+                if ui.add(egui::Button::new("Match Setup")).clicked() {
+                    self.match_dialog_open = true;
+                }
+                if ui.add(egui::Button::new("Teams")).clicked() {
+                    self.teams_dialog_open = true;
+                }
+                if ui.add(egui::Button::new("Event")).clicked() {
+                    self.event_dialog_open = true;
+                }
+                if ui.add(egui::Button::new("Keys")).clicked() {
+                    self.keymap_dialog_open = true;
+                }
+                if ui.add(egui::Button::new("Appearance")).clicked() {
+                    self.theme_dialog_open = true;
+                }
+                ui.separator();
+                ui.checkbox(&mut self.audience_open, "Audience Display");
+                if ui.add_enabled(self.audience_open, egui::Checkbox::new(&mut self.fullscreen, "Fullscreen")).changed() && !self.audience_open {
+                    self.fullscreen = false;
+                }
+                ui.separator();
+                ui.label(format!("{:?} - {}", self.bjj_match.get_match_state(), self.bjj_match.time.get_remaining_time_string()));
+            });
+        });
+
+        egui::SidePanel::left("competitor_controls").show(ctx, |ui| {
+            for competitor in [CompetitorNumber::One, CompetitorNumber::Two] {
+                ui.heading(self.bjj_match.info.get_competitor(competitor).get_display_name());
+                egui::Grid::new(("competitor_controls", competitor as usize))
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        let buttons = [
+                            ("+2", Action::AddPoints(competitor, 2)),
+                            ("+3", Action::AddPoints(competitor, 3)),
+                            ("+4", Action::AddPoints(competitor, 4)),
+                            ("Adv. +", Action::AddAdvantage(competitor)),
+                            ("Pen. +", Action::AddPenalty(competitor)),
+                            ("Point -", Action::SubtractPoint(competitor)),
+                            ("Adv. -", Action::SubtractAdvantage(competitor)),
+                            ("Pen. -", Action::SubtractPenalty(competitor)),
+                            ("Submission", Action::Win(competitor, WinMethod::Submission)),
+                        ];
+                        for (index, (label, action)) in buttons.into_iter().enumerate() {
+                            if ui.add_sized([72.0, 28.0], egui::Button::new(label)).clicked() {
+                                actions.push(action);
+                            }
+                            if index % 3 == 2 {
+                                ui.end_row();
+                            }
+                        }
+                    });
+                ui.horizontal(|ui| {
+                    for kind in StoppageKind::iter() {
+                        let running = self.bjj_match.stoppages.is_running(competitor, kind);
+                        let label = format!("{} {}", kind.get_display_name(),
+                            MatchTime::format_millis(self.bjj_match.stoppages.get_remaining_millis(competitor, kind)));
+                        let enabled = running || self.bjj_match.can_start_stoppage(competitor, kind);
+                        if ui.add_enabled(enabled, egui::SelectableLabel::new(running, label)).clicked() {
+                            actions.push(Action::ToggleStoppage(competitor, kind));
+                        }
+                    }
+                });
+                ui.separator();
+            }
+
+            ui.heading("Clock");
+            ui.add_enabled_ui(self.bjj_match.can_adjust_clock(), |ui| {
+                ui.horizontal(|ui| {
+                    for seconds in [-10, -1, 1, 10] {
+                        if ui.add_sized([40.0, 28.0], egui::Button::new(format!("{:+}s", seconds))).clicked() {
+                            actions.push(Action::AdjustClock(seconds));
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.remaining_time_input).hint_text("mm:ss").desired_width(72.0));
+                    let remaining = MatchTime::parse_millis(&self.remaining_time_input);
+                    if ui.add_enabled(remaining.is_some(), egui::Button::new("Set Remaining")).clicked() {
+                        if let Some(millis) = remaining {
+                            actions.push(Action::SetRemainingTime(millis));
+                        }
+                        self.remaining_time_input.clear();
+                    }
+                });
+            });
+
+            if self.bjj_match.ebi.is_active() {
+                ui.separator();
+                ui.heading("EBI Overtime");
+                if let Some(turn) = self.bjj_match.get_ebi_turn_description() {
+                    ui.label(turn);
+                }
+                let current = self.bjj_match.ebi.get_current_turn().map(|turn| turn.position);
+                ui.add_enabled_ui(self.bjj_match.can_set_ebi_position(), |ui| {
+                    ui.horizontal(|ui| {
+                        for position in EbiPosition::iter() {
+                            if ui.add(egui::SelectableLabel::new(current == Some(position), position.get_display_name())).clicked() {
+                                actions.push(Action::SetEbiPosition(position));
+                            }
+                        }
+                    });
+                });
+                ui.add_enabled_ui(current.is_some() && !self.bjj_match.is_frozen(), |ui| {
+                    ui.horizontal(|ui| {
+                        for outcome in [EbiOutcome::Submission, EbiOutcome::Escape] {
+                            if ui.add_sized([72.0, 28.0], egui::Button::new(outcome.get_display_name())).clicked() {
+                                actions.push(Action::RecordEbiOutcome(outcome));
+                            }
+                        }
+                    });
+                });
+                for competitor in [CompetitorNumber::One, CompetitorNumber::Two] {
+                    let summary = self.bjj_match.ebi.get_summary(competitor);
+                    if !summary.is_empty() {
+                        ui.label(format!("{}: {}", self.bjj_match.info.get_competitor(competitor).get_display_name(), summary.join(", ")));
+                    }
+                }
+            }
+        });
+
+        egui::SidePanel::right("match_history").show(ctx, |ui| {
+            ui.heading("History");
+            if let Some(result) = &self.bjj_match.result {
+                ui.strong(format!("{} {} wins by {}",
+                    MatchTime::format_millis(result.time_elapsed_millis),
+                    self.bjj_match.info.get_competitor(result.winner).get_display_name(),
+                    result.method.get_display_name()));
+            }
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for event in self.bjj_match.log.events.iter().rev() {
+                    ui.label(event.get_description(&self.bjj_match.info));
+                }
+            });
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.ui(ui);
+        });
+
+        for action in actions {
+            self.apply_action(action);
         }
-        if ctx.input(|i| i.key_pressed(Key::D)) {
-            self.bjj_match.add_points(4, CompetitorNumber::Two);
+    }
+
+    fn handle_input(&mut self, ctx: &egui::Context) {
+        if let Some(action) = self.keymap_capture {
+            self.capture_key(ctx, action);
+            return;
         }
-        if ctx.input(|i| i.key_pressed(Key::F)) {
-            self.bjj_match.add_advantage( CompetitorNumber::Two);
+        if ctx.wants_keyboard_input() || matches!(self.app_state, AppState::NewMatchDialog) {
+            return;
         }
-        if ctx.input(|i| i.key_pressed(Key::G)) {
-            self.bjj_match.add_penalty( CompetitorNumber::Two);
+
+        let actions: Vec<Action> = ctx.input(|i| i.events.iter()
+            .filter_map(|event| match event {
+                egui::Event::Key { key, pressed: true, modifiers, .. } => self.keymap.action_for(&KeyChord::from_egui(*key, *modifiers)),
+                _ => None
+            })
+            .collect());
+
+        for action in actions {
+            self.apply_action(action);
         }
-        if ctx.input(|i| i.key_pressed(Key::H)) {
-            self.bjj_match.subtract_point( CompetitorNumber::Two);
+    }
+
+    fn capture_key(&mut self, ctx: &egui::Context, action: Action) {
+        let pressed = ctx.input_mut(|i| {
+            let index = i.events.iter().position(|event| matches!(event, egui::Event::Key { pressed: true, .. }))?;
+            match i.events.remove(index) {
+                egui::Event::Key { key, modifiers, .. } => Some((key, modifiers)),
+                _ => None
+            }
+        });
+        let Some((key, modifiers)) = pressed else {
+            return;
+        };
+
+        self.keymap_capture = None;
+        if key == Key::Escape && modifiers.is_none() {
+            return;
         }
-        if ctx.input(|i| i.key_pressed(Key::J)) {
-            self.bjj_match.subtract_advantage( CompetitorNumber::Two);
+
+        let chord = KeyChord::from_egui(key, modifiers);
+        if chord.is_reserved() {
+            self.keymap_problems = vec![format!("{} is reserved for match setup", chord)];
+            return;
         }
-        if ctx.input(|i| i.key_pressed(Key::K)) {
-            self.bjj_match.subtract_penalty( CompetitorNumber::Two);
+        if let Some(replaced) = self.keymap.bind(chord.clone(), action) {
+            self.keymap_problems = vec![format!("{} was moved from {} to {}", chord, replaced.get_display_name(), action.get_display_name())];
         }
-        if ctx.input(|i| i.key_pressed(Key::Space)) {
-            match self.app_state {
-                AppState::NewMatchDialog => {},
-                AppState::Ready => {
-                    self.audio.play_air_horn();
-                    self.bjj_match.start();
-                    self.app_state = AppState::InProgress
-                },
-                AppState::InProgress => {
-                    self.bjj_match.toggle_start_stop();
-                }
-            }
+    }
 
+    pub fn apply_action(&mut self, action: Action) {
+        match action {
+            Action::AddPoints(competitor, points) => self.bjj_match.add_points(points, competitor),
+            Action::SubtractPoint(competitor) => self.bjj_match.subtract_point(competitor),
+            Action::AddAdvantage(competitor) => self.bjj_match.add_advantage(competitor),
+            Action::SubtractAdvantage(competitor) => self.bjj_match.subtract_advantage(competitor),
+            Action::AddPenalty(competitor) => self.bjj_match.add_penalty(competitor),
+            Action::SubtractPenalty(competitor) => self.bjj_match.subtract_penalty(competitor),
+            Action::Win(competitor, method) => self.bjj_match.finish(competitor, method),
+            Action::RecordResult => {
+                if !self.bjj_match.is_frozen() {
+                    self.bjj_match.stop();
+                    self.result_dialog_open = true;
+                }
+            },
+            Action::StartStop => {
+                match self.app_state {
+                    AppState::NewMatchDialog | AppState::AwaitingDecision => {},
+                    AppState::Finished => {
+                        self.new_match();
+                    },
+                    AppState::Ready => {
+                        self.audio.play_air_horn();
+                        self.bjj_match.start();
+                        self.app_state = AppState::InProgress
+                    },
+                    AppState::InProgress => {
+                        self.bjj_match.toggle_start_stop();
+                    }
+                }
+            },
+            Action::NewMatch => {
+                if let AppState::Finished | AppState::Ready = self.app_state {
+                    self.new_match();
+                }
+            },
+            Action::AdjustClock(seconds) => self.bjj_match.adjust_clock(seconds),
+            Action::SetRemainingTime(millis) => self.bjj_match.set_remaining_time(millis),
+            Action::ToggleStoppage(competitor, kind) => self.bjj_match.toggle_stoppage(competitor, kind),
+            Action::SetEbiPosition(position) => self.bjj_match.set_ebi_position(position),
+            Action::RecordEbiOutcome(outcome) => self.bjj_match.record_ebi_outcome(outcome),
+            Action::Undo => self.bjj_match.undo(),
+            Action::Redo => self.bjj_match.redo(),
+            Action::ToggleFullscreen => self.fullscreen = !self.fullscreen
         }
     }
 
     fn ui(&mut self, ui: &mut egui::Ui) {
-        let scale_factor = ui.clip_rect().width() / 600.0;
-        let match_grid = calc_grids(ui.clip_rect());
-
-        ui.painter().rect_filled(match_grid.full, Rounding::none(), Color32::BLACK);
-
-        ui.painter().rect_filled(match_grid.competitor_one.left, Rounding::none(), self.color_scheme.competitor_one_bg);
-        ui.painter().rect_filled(match_grid.competitor_one.advantages, Rounding::none(), self.color_scheme.competitor_one_adv_bg);
-        ui.painter().rect_filled(match_grid.competitor_one.penalties, Rounding::none(), self.color_scheme.competitor_one_pen_bg);
-        ui.painter().rect_filled(match_grid.competitor_one.points, Rounding::none(), self.color_scheme.competitor_one_points_bg);
-        ui.painter().rect_filled(match_grid.competitor_two.left, Rounding::none(), self.color_scheme.competitor_two_bg);
-        ui.painter().rect_filled(match_grid.competitor_two.advantages, Rounding::none(), self.color_scheme.competitor_two_adv_bg);
-        ui.painter().rect_filled(match_grid.competitor_two.penalties, Rounding::none(), self.color_scheme.competitor_two_pen_bg);
-        ui.painter().rect_filled(match_grid.competitor_two.points, Rounding::none(), self.color_scheme.competitor_two_points_bg);
-        ui.painter().rect_filled(match_grid.time.full, Rounding::none(), self.color_scheme.bottom_pane_bg);
+        let layout = self.layout.layout();
+        let scale_factor = ui.clip_rect().width() / 600.0 * layout.text_scale();
+        let match_grid = layout.calc_grids(ui.clip_rect());
+        let score = self.bjj_match.get_score();
+
+        ui.painter().rect_filled(layout.background(&match_grid), Rounding::ZERO, Color32::BLACK);
+
+        ui.painter().rect_filled(match_grid.competitor_one.left, Rounding::ZERO, self.color_scheme.competitor_one_bg);
+        ui.painter().rect_filled(match_grid.competitor_one.advantages, Rounding::ZERO, self.color_scheme.competitor_one_adv_bg);
+        ui.painter().rect_filled(match_grid.competitor_one.penalties, Rounding::ZERO, self.color_scheme.competitor_one_pen_bg);
+        ui.painter().rect_filled(match_grid.competitor_one.points, Rounding::ZERO, self.color_scheme.competitor_one_points_bg);
+        ui.painter().rect_filled(match_grid.competitor_two.left, Rounding::ZERO, self.color_scheme.competitor_two_bg);
+        ui.painter().rect_filled(match_grid.competitor_two.advantages, Rounding::ZERO, self.color_scheme.competitor_two_adv_bg);
+        ui.painter().rect_filled(match_grid.competitor_two.penalties, Rounding::ZERO, self.color_scheme.competitor_two_pen_bg);
+        ui.painter().rect_filled(match_grid.competitor_two.points, Rounding::ZERO, self.color_scheme.competitor_two_points_bg);
+        ui.painter().rect_filled(match_grid.time.full, Rounding::ZERO, self.color_scheme.bottom_pane_bg);
 
         ui.painter().text(
             match_grid.competitor_one.name.left_center().add(Vec2 { x: 10.0 * scale_factor, y: 0.0}),
             Align2::LEFT_CENTER,
             self.bjj_match.info.competitor_one.get_display_name(),
-            egui::FontId { size: self.font_sizes.competitor_name * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_name * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_one_name);
 
         ui.painter().text(
             match_grid.competitor_one.team.left_center().add( Vec2 { x: 10.0 * scale_factor, y: 0.0 }),
             Align2::LEFT_CENTER,
             self.bjj_match.info.competitor_one.team_name.as_str(),
-            egui::FontId { size: self.font_sizes.competitor_team * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_team * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_one_team);
 
         ui.painter().text(
             match_grid.competitor_one.advantages.center_top().add(Vec2 { x: 0.0, y: 2.0 * scale_factor }),
             Align2::CENTER_TOP,
             "Adv.",
-            egui::FontId { size: self.font_sizes.competitor_adv_label * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_adv_label * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_one_adv);
 
         ui.painter().text(
             match_grid.competitor_one.advantages.center(),
             Align2::CENTER_CENTER,
-            self.bjj_match.score.competitor_one_score.advantages.to_string(),
-            egui::FontId { size: self.font_sizes.competitor_adv * scale_factor, ..Default::default()},
+            score.competitor_one_score.advantages.to_string(),
+            egui::FontId::new(self.font_sizes.competitor_adv * scale_factor, FontRole::Numbers.family()),
             self.color_scheme.competitor_one_adv);
 
         ui.painter().text(
             match_grid.competitor_one.penalties.center_top().add(Vec2 { x: 0.0, y: 2.0 * scale_factor }),
             Align2::CENTER_TOP,
             "Pen.",
-            egui::FontId { size: self.font_sizes.competitor_pen_label * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_pen_label * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_one_pen);
 
         ui.painter().text(
             match_grid.competitor_one.penalties.center(),
             Align2::CENTER_CENTER,
-            self.bjj_match.score.competitor_one_score.penalties.to_string(),
-            egui::FontId { size: self.font_sizes.competitor_pen * scale_factor, ..Default::default()},
+            score.competitor_one_score.penalties.to_string(),
+            egui::FontId::new(self.font_sizes.competitor_pen * scale_factor, FontRole::Numbers.family()),
             self.color_scheme.competitor_one_pen);
 
         ui.painter().text(
             match_grid.competitor_one.points.center(),
             Align2::CENTER_CENTER,
-            self.bjj_match.score.competitor_one_score.points.to_string(),
-            egui::FontId { size: self.font_sizes.competitor_points * scale_factor, ..Default::default()},
+            score.competitor_one_score.points.to_string(),
+            egui::FontId::new(self.font_sizes.competitor_points * scale_factor, FontRole::Numbers.family()),
             self.color_scheme.competitor_one_points);
 
         ui.painter().text(
             match_grid.competitor_two.name.left_center().add(Vec2 { x: 10.0 * scale_factor, y: 0.0}),
             Align2::LEFT_CENTER,
             self.bjj_match.info.competitor_two.get_display_name(),
-            egui::FontId { size: self.font_sizes.competitor_name * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_name * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_two_name);
 
         ui.painter().text(
             match_grid.competitor_two.team.left_center().add( Vec2 { x: 10.0 * scale_factor, y: 0.0 }),
             Align2::LEFT_CENTER,
             self.bjj_match.info.competitor_two.team_name.as_str(),
-            egui::FontId { size: self.font_sizes.competitor_team * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_team * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_two_team);
 
         ui.painter().text(
             match_grid.competitor_two.advantages.center_top().add(Vec2 { x: 0.0, y: 2.0 * scale_factor }),
             Align2::CENTER_TOP,
             "Adv.",
-            egui::FontId { size: self.font_sizes.competitor_adv_label * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_adv_label * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_two_adv);
 
         ui.painter().text(
             match_grid.competitor_two.advantages.center(),
             Align2::CENTER_CENTER,
-            self.bjj_match.score.competitor_two_score.advantages.to_string(),
-            egui::FontId { size: self.font_sizes.competitor_adv * scale_factor, ..Default::default()},
+            score.competitor_two_score.advantages.to_string(),
+            egui::FontId::new(self.font_sizes.competitor_adv * scale_factor, FontRole::Numbers.family()),
             self.color_scheme.competitor_two_adv);
 
         ui.painter().text(
             match_grid.competitor_two.penalties.center_top().add(Vec2 { x: 0.0, y: 2.0 * scale_factor }),
             Align2::CENTER_TOP,
             "Pen.",
-            egui::FontId { size: self.font_sizes.competitor_pen_label * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.competitor_pen_label * scale_factor, FontRole::Names.family()),
             self.color_scheme.competitor_two_pen);
 
         ui.painter().text(
             match_grid.competitor_two.penalties.center(),
             Align2::CENTER_CENTER,
-            self.bjj_match.score.competitor_two_score.penalties.to_string(),
-            egui::FontId { size: self.font_sizes.competitor_pen * scale_factor, ..Default::default()},
+            score.competitor_two_score.penalties.to_string(),
+            egui::FontId::new(self.font_sizes.competitor_pen * scale_factor, FontRole::Numbers.family()),
             self.color_scheme.competitor_two_pen);
 
         ui.painter().text(
             match_grid.competitor_two.points.center(),
             Align2::CENTER_CENTER,
-            self.bjj_match.score.competitor_two_score.points.to_string(),
-            egui::FontId { size: self.font_sizes.competitor_points * scale_factor, ..Default::default()},
+            score.competitor_two_score.points.to_string(),
+            egui::FontId::new(self.font_sizes.competitor_points * scale_factor, FontRole::Numbers.family()),
             self.color_scheme.competitor_two_points);
 
-        if let Some(flag) = self.flags.get(&self.bjj_match.info.competitor_one.country) {
-            if let Some(handle) = &flag.handle {
-                ui.painter().image(
+        for (competitor, emblem_rect) in [
+            (&self.bjj_match.info.competitor_one, match_grid.competitor_one.flag),
+            (&self.bjj_match.info.competitor_two, match_grid.competitor_two.flag)
+        ] {
+            let logo = match self.event.emblem {
+                EmblemMode::TeamLogo => self.teams.find(&competitor.team_name).and_then(Team::get_logo_path),
+                EmblemMode::Flag => None
+            };
+            let emblem = match logo {
+                Some(path) => self.logos.get(path, LOGO_HEIGHT).map(|handle| {
+                    let size = handle.size_vec2();
+                    (handle, emblem_rect.shrink_to_aspect_ratio(size.x / size.y))
+                }),
+                None => self.flags.get(competitor.country, SCOREBOARD_FLAG_HEIGHT)
+                    .map(|handle| (handle, emblem_rect.shrink_to_aspect_ratio(2.0)))
+            };
+
+            match emblem {
+                Some((handle, image_rect)) => ui.painter().image(
                     handle.id(),
-                    match_grid.competitor_one.flag.shrink_to_aspect_ratio(2.0).shrink(5.0 * scale_factor),
+                    image_rect.shrink(5.0 * scale_factor),
                     Rect::from_min_max(Pos2 { x: 0.0, y: 0.0 }, Pos2 { x: 1.0, y: 1.0 }),
                     Color32::WHITE
-                );
+                ),
+                None => ui.painter().rect_filled(
+                    emblem_rect.shrink_to_aspect_ratio(2.0).shrink(5.0 * scale_factor),
+                    Rounding::ZERO,
+                    self.color_scheme.flag_placeholder)
             }
         }
 
+        for (competitor, grid) in [(CompetitorNumber::One, &match_grid.competitor_one), (CompetitorNumber::Two, &match_grid.competitor_two)] {
+            let running: Vec<String> = self.bjj_match.stoppages.running()
+                .filter(|timer| timer.competitor == competitor)
+                .map(|timer| format!("{} {}", timer.kind.get_display_name(), timer.time.get_remaining_time_string()))
+                .collect();
+            if running.is_empty() {
+                continue;
+            }
 
-        if let Some(flag) = self.flags.get(&self.bjj_match.info.competitor_two.country) {
-            if let Some(handle) = &flag.handle {
-                ui.painter().image(
-                    handle.id(),
-                    match_grid.competitor_two.flag.shrink_to_aspect_ratio(2.0).shrink(5.0 * scale_factor),
-                    Rect::from_min_max(Pos2 { x: 0.0, y: 0.0 }, Pos2 { x: 1.0, y: 1.0 }),
-                    Color32::WHITE
-                );
+            ui.painter().text(
+                grid.team.right_center().add(Vec2 { x: -10.0 * scale_factor, y: 0.0 }),
+                Align2::RIGHT_CENTER,
+                running.join("  "),
+                egui::FontId::new(self.font_sizes.competitor_team * scale_factor, FontRole::Clock.family()),
+                self.color_scheme.stoppage_timer);
+        }
+
+        for (competitor, grid) in [(CompetitorNumber::One, &match_grid.competitor_one), (CompetitorNumber::Two, &match_grid.competitor_two)] {
+            let summary = self.bjj_match.ebi.get_summary(competitor);
+            if summary.is_empty() {
+                continue;
             }
+
+            ui.painter().text(
+                grid.name.right_center().add(Vec2 { x: -10.0 * scale_factor, y: 0.0 }),
+                Align2::RIGHT_CENTER,
+                summary.join("  "),
+                egui::FontId::new(self.font_sizes.competitor_team * scale_factor, FontRole::Clock.family()),
+                self.color_scheme.time);
         }
 
         ui.painter().text(
             match_grid.time.time.center(),
             Align2::CENTER_CENTER,
             self.bjj_match.time.get_remaining_time_string(),
-            egui::FontId { size: self.font_sizes.time * scale_factor, ..Default::default()},
+            egui::FontId::new(self.font_sizes.time * scale_factor, FontRole::Clock.family()),
             self.color_scheme.time);
 
+        if let Some(period) = self.bjj_match.get_period_name() {
+            let period = match self.bjj_match.ebi.get_current_turn() {
+                Some(turn) => format!("{} - {}", period, turn.position.get_display_name()),
+                None => period
+            };
+            ui.painter().text(
+                match_grid.time.time.center_top().add(Vec2 { x: 0.0, y: 2.0 * scale_factor }),
+                Align2::CENTER_TOP,
+                period,
+                egui::FontId::new(self.font_sizes.competitor_adv_label * scale_factor, FontRole::Names.family()),
+                self.color_scheme.time);
+        }
+
         ui.painter().text(
             match_grid.time.fight_info_heading.left_center(),
             Align2::LEFT_CENTER,
-            "Fight Info",
-            egui::FontId { size: self.font_sizes.fight_info_heading * scale_factor, ..Default::default()},
+            self.event.get_heading(&self.bjj_match.info),
+            egui::FontId::new(self.font_sizes.fight_info_heading * scale_factor, FontRole::Names.family()),
             self.color_scheme.fight_info_heading);
 
         ui.painter().text(
             match_grid.time.fight_info_sub_heading.left_center(),
             Align2::LEFT_CENTER,
-            "Fight Sub Heading",
-            egui::FontId { size: self.font_sizes.fight_info_sub_heading * scale_factor, ..Default::default()},
+            self.event.get_subheading(&self.bjj_match.info),
+            egui::FontId::new(self.font_sizes.fight_info_sub_heading * scale_factor, FontRole::Names.family()),
             self.color_scheme.fight_info_sub_heading);
+
+        if let Some(path) = self.event.get_current_logo_path(ui.input(|i| i.time)) {
+            if let Some(handle) = self.logos.get(path, LOGO_HEIGHT) {
+                let size = handle.size_vec2();
+                ui.painter().image(
+                    handle.id(),
+                    match_grid.time.logo.shrink(5.0 * scale_factor).shrink_to_aspect_ratio(size.x / size.y),
+                    Rect::from_min_max(Pos2 { x: 0.0, y: 0.0 }, Pos2 { x: 1.0, y: 1.0 }),
+                    Color32::WHITE
+                );
+            }
+        }
+
+        if let Some(result) = &self.bjj_match.result {
+            let (winner, banner_bg) = match result.winner {
+                CompetitorNumber::One => (&self.bjj_match.info.competitor_one, self.color_scheme.competitor_one_points_bg),
+                CompetitorNumber::Two => (&self.bjj_match.info.competitor_two, self.color_scheme.competitor_two_points_bg)
+            };
+            let period = self.bjj_match.get_period_name().map(|period| format!(" in {}", period)).unwrap_or_default();
+            let banner_height = match_grid.competitor_one.points.height() * 0.5;
+            let banner = Rect::from_center_size(
+                Pos2 { x: match_grid.full.center().x, y: match_grid.competitor_one.full.max.y },
+                Vec2 { x: match_grid.full.width(), y: banner_height });
+
+            ui.painter().rect_filled(banner, Rounding::ZERO, banner_bg);
+            ui.painter().text(
+                banner.center(),
+                Align2::CENTER_CENTER,
+                format!("{} wins by {}{} ({})",
+                    winner.get_display_name(),
+                    result.method.get_display_name(),
+                    period,
+                    MatchTime::format_millis(result.time_elapsed_millis)),
+                egui::FontId::new(self.font_sizes.winner_banner * scale_factor, FontRole::Names.family()),
+                self.color_scheme.winner_banner);
+        }
+    }
+
+    fn new_match(&mut self) {
+        self.bjj_match.reset();
+        self.match_store.clear();
+        self.app_state = AppState::NewMatchDialog;
+        self.match_dialog_open = true;
+    }
+
+    fn draw_referee_decision_modal(&mut self, ctx: &egui::Context) {
+        let mut winner = None;
+
+        egui::Window::new("Referee Decision Required")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label("The scores are level. Select the winner chosen by the referee.");
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new(self.bjj_match.info.competitor_one.get_display_name())).clicked() {
+                        winner = Some(CompetitorNumber::One);
+                    }
+                    if ui.add(egui::Button::new(self.bjj_match.info.competitor_two.get_display_name())).clicked() {
+                        winner = Some(CompetitorNumber::Two);
+                    }
+                });
+            });
+
+        if let Some(winner) = winner {
+            self.bjj_match.record_referee_decision(winner);
+        }
+    }
+
+    fn draw_result_modal(&mut self, ctx: &egui::Context) {
+        let mut record = false;
+        let mut cancel = false;
+
+        egui::Window::new("Record Result")
+            .open(&mut self.result_dialog_open)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("result_grid")
+                    .num_columns(2)
+                    .spacing([40.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        let winner = ui.label("Winner");
+                        egui::ComboBox::from_id_source(winner.id)
+                            .selected_text(match self.result_winner {
+                                CompetitorNumber::One => self.bjj_match.info.competitor_one.get_display_name(),
+                                CompetitorNumber::Two => self.bjj_match.info.competitor_two.get_display_name()
+                            })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.result_winner, CompetitorNumber::One, self.bjj_match.info.competitor_one.get_display_name());
+                                ui.selectable_value(&mut self.result_winner, CompetitorNumber::Two, self.bjj_match.info.competitor_two.get_display_name());
+                            });
+                        ui.end_row();
+
+                        let method = ui.label("Method");
+                        egui::ComboBox::from_id_source(method.id)
+                            .selected_text(self.result_method.get_display_name())
+                            .show_ui(ui, |ui| {
+                                for value in WinMethod::manual() {
+                                    ui.selectable_value(&mut self.result_method, value, value.get_display_name());
+                                }
+                            });
+                        ui.end_row();
+
+                        if ui.add(egui::Button::new("Record Result")).clicked() {
+                            record = true;
+                        }
+                        if ui.add(egui::Button::new("Cancel")).clicked() {
+                            cancel = true;
+                        }
+                        ui.end_row();
+                    });
+            });
+
+        if record {
+            self.bjj_match.finish(self.result_winner, self.result_method);
+        }
+        if record || cancel {
+            self.result_dialog_open = false;
+        }
+    }
+
+    fn draw_team_selector(ui: &mut egui::Ui, id_source: &str, team_name: &mut String, teams: &TeamRegistry) {
+        if teams.teams.is_empty() {
+            return;
+        }
+
+        egui::ComboBox::from_id_source(id_source)
+            .selected_text("Registry")
+            .show_ui(ui, |ui| {
+                for team in &teams.teams {
+                    if ui.selectable_label(team.name == *team_name, team.name.as_str()).clicked() {
+                        *team_name = team.name.clone();
+                    }
+                }
+            });
+    }
+
+    fn draw_teams_modal(&mut self, ctx: &egui::Context) {
+        let mut save = false;
+        let mut remove = None;
+
+        egui::Window::new("Teams")
+            .open(&mut self.teams_dialog_open)
+            .show(ctx, |ui| {
+                if let Some(dir) = textures::logo_dir() {
+                    ui.label(format!("Logo files (SVG or PNG) are read from {}", dir.display()));
+                }
+                egui::Grid::new("teams_grid")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Name");
+                        ui.strong("Code");
+                        ui.strong("Logo");
+                        ui.end_row();
+
+                        for (index, team) in self.teams.teams.iter_mut().enumerate() {
+                            ui.text_edit_singleline(&mut team.name);
+                            ui.add(egui::TextEdit::singleline(&mut team.short_code).desired_width(60.0));
+                            ui.add(egui::TextEdit::singleline(&mut team.logo).hint_text("logo.svg"));
+                            if ui.add(egui::Button::new("Remove")).clicked() {
+                                remove = Some(index);
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new("Add Team")).clicked() {
+                        self.teams.teams.push(Team::default());
+                    }
+                    if ui.add(egui::Button::new("Save")).clicked() {
+                        save = true;
+                    }
+                });
+            });
+
+        if let Some(index) = remove {
+            self.teams.teams.remove(index);
+        }
+        if save {
+            if let Err(e) = self.teams.save() {
+                println!("Error saving teams: {:#}", e);
+            }
+            self.logos.reload();
+        }
+    }
+
+    fn draw_keymap_modal(&mut self, ctx: &egui::Context) {
+        let mut save = false;
+        let mut reset = false;
+        let mut clear = None;
+
+        egui::Window::new("Keys")
+            .open(&mut self.keymap_dialog_open)
+            .show(ctx, |ui| {
+                for problem in &self.keymap_problems {
+                    ui.colored_label(ui.visuals().warn_fg_color, problem);
+                }
+                if let Some(action) = self.keymap_capture {
+                    ui.strong(format!("Press a key for {}, or Escape to cancel", action.get_display_name()));
+                }
+
+                egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                    egui::Grid::new("keymap_grid")
+                        .num_columns(4)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for action in Action::all() {
+                                let chords: Vec<String> = self.keymap.chords_for(action).iter().map(|chord| chord.to_string()).collect();
+                                ui.label(action.get_display_name());
+                                ui.label(chords.join(", "));
+                                if ui.add(egui::Button::new("Add Key")).clicked() {
+                                    self.keymap_capture = Some(action);
+                                }
+                                if ui.add_enabled(!chords.is_empty(), egui::Button::new("Clear")).clicked() {
+                                    clear = Some(action);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new("Reset to Defaults")).clicked() {
+                        reset = true;
+                    }
+                    if ui.add(egui::Button::new("Save")).clicked() {
+                        save = true;
+                    }
+                });
+            });
+
+        if let Some(action) = clear {
+            self.keymap.unbind(action);
+        }
+        if reset {
+            self.keymap = Keymap::default();
+            self.keymap_problems.clear();
+        }
+        if save {
+            self.keymap_problems = self.keymap.validate();
+            if let Err(e) = self.keymap.save() {
+                println!("Error saving keymap: {:#}", e);
+                self.keymap_problems.push(format!("Error saving keymap: {:#}", e));
+            }
+        }
+        if !self.keymap_dialog_open {
+            self.keymap_capture = None;
+        }
+    }
+
+    fn apply_theme(&mut self) {
+        let theme = self.themes.get_current();
+        self.color_scheme = theme.colors;
+        self.font_sizes = theme.font_sizes;
+        self.layout = theme.layout;
+        self.theme_name = self.themes.current.clone();
+    }
+
+    fn draw_theme_modal(&mut self, ctx: &egui::Context) {
+        #[cfg(feature = "http-api")]
+        let previous_colors = self.color_scheme.clone();
+        let mut selected = None;
+        let mut save = false;
+        let mut delete = false;
+        let mut revert = false;
+        let mut remove_fallback = None;
+        let mut apply_fonts = false;
+
+        egui::Window::new("Appearance")
+            .open(&mut self.theme_dialog_open)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Theme");
+                    egui::ComboBox::from_id_source("theme_selector")
+                        .selected_text(&self.themes.current)
+                        .show_ui(ui, |ui| {
+                            for name in self.themes.themes.keys() {
+                                if ui.selectable_label(*name == self.themes.current, name).clicked() {
+                                    selected = Some(name.clone());
+                                }
+                            }
+                        });
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Layout");
+                    egui::ComboBox::from_id_source("layout_selector")
+                        .selected_text(self.layout.get_display_name())
+                        .show_ui(ui, |ui| {
+                            for layout in LayoutKind::iter() {
+                                ui.selectable_value(&mut self.layout, layout, layout.get_display_name());
+                            }
+                        });
+                });
+
+                egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                    ui.collapsing("Colours", |ui| {
+                        egui::Grid::new("theme_colors_grid")
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for (name, color) in self.color_scheme.colors_mut() {
+                                    ui.label(name.replace('_', " "));
+                                    egui::color_picker::color_edit_button_srgba(ui, color, egui::color_picker::Alpha::OnlyBlend);
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                    ui.collapsing("Font Sizes", |ui| {
+                        egui::Grid::new("theme_font_sizes_grid")
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for (name, size) in self.font_sizes.sizes_mut() {
+                                    ui.label(name.replace('_', " "));
+                                    ui.add(egui::DragValue::new(size).clamp_range(4.0..=400.0).speed(0.5));
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                    ui.collapsing("Fonts", |ui| {
+                        egui::Grid::new("theme_fonts_grid")
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.strong("Text");
+                                ui.strong("Font File (TTF/OTF)");
+                                ui.strong("Vertical Offset");
+                                ui.end_row();
+
+                                for role in FontRole::iter() {
+                                    let choice = self.fonts.get_choice_mut(role);
+                                    ui.label(role.get_display_name());
+                                    ui.add(egui::TextEdit::singleline(&mut choice.path).hint_text("Built-in"));
+                                    ui.add(egui::DragValue::new(&mut choice.y_offset).clamp_range(-1.0..=1.0).speed(0.005));
+                                    ui.end_row();
+                                }
+                            });
+
+                        ui.label("Fallback fonts for characters the fonts above are missing");
+                        if !self.fonts_cover_cjk {
+                            ui.colored_label(ui.visuals().warn_fg_color, "No Chinese, Japanese or Korean font was found. Add one here to show those names.");
+                        }
+                        for (index, path) in self.fonts.fallbacks.iter_mut().enumerate() {
+                            ui.horizontal(|ui| {
+                                ui.text_edit_singleline(path);
+                                if ui.add(egui::Button::new("Remove")).clicked() {
+                                    remove_fallback = Some(index);
+                                }
+                            });
+                        }
+                        ui.horizontal(|ui| {
+                            if ui.add(egui::Button::new("Add Fallback")).clicked() {
+                                self.fonts.fallbacks.push(String::new());
+                            }
+                            if ui.add(egui::Button::new("Apply Fonts")).clicked() {
+                                apply_fonts = true;
+                            }
+                        });
+                    });
+                });
+
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.theme_name).hint_text("Theme name").desired_width(160.0));
+                    if ui.add_enabled(!self.theme_name.trim().is_empty(), egui::Button::new("Save Theme")).clicked() {
+                        save = true;
+                    }
+                    if ui.add_enabled(self.themes.themes.len() > 1, egui::Button::new("Delete Theme")).clicked() {
+                        delete = true;
+                    }
+                    if ui.add(egui::Button::new("Revert")).clicked() {
+                        revert = true;
+                    }
+                });
+            });
+
+        if let Some(index) = remove_fallback {
+            self.fonts.fallbacks.remove(index);
+        }
+        if apply_fonts {
+            self.apply_fonts(ctx);
+            if let Err(e) = self.fonts.save() {
+                println!("Error saving fonts: {:#}", e);
+            }
+        }
+        if let Some(name) = &selected {
+            self.themes.current = name.clone();
+            self.apply_theme();
+        }
+        if delete {
+            let current = self.themes.current.clone();
+            self.themes.remove(&current);
+            self.apply_theme();
+        }
+        if revert {
+            self.apply_theme();
+        }
+        if save {
+            let theme = Theme { colors: self.color_scheme.clone(), font_sizes: self.font_sizes.clone(), layout: self.layout };
+            self.themes.store(self.theme_name.trim(), theme);
+            self.theme_name = self.themes.current.clone();
+        }
+        if save || delete || selected.is_some() {
+            if let Err(e) = self.themes.save() {
+                println!("Error saving themes: {:#}", e);
+            }
+        }
+
+        #[cfg(feature = "http-api")]
+        if self.color_scheme != previous_colors {
+            if let Some(server) = &self.api_server {
+                server.set_color_scheme(&self.color_scheme);
+            }
+        }
     }
 
-    fn draw_match_info_dialog(heading: &str, info: &mut MatchInformation, ui: &mut egui::Ui) {
+    fn draw_event_modal(&mut self, ctx: &egui::Context) {
+        let mut save = false;
+        let mut remove = None;
+
+        egui::Window::new("Event Settings")
+            .open(&mut self.event_dialog_open)
+            .show(ctx, |ui| {
+                egui::Grid::new("event_grid")
+                    .num_columns(2)
+                    .spacing([40.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        let emblem = ui.label("Competitor Emblem");
+                        egui::ComboBox::from_id_source(emblem.id)
+                            .selected_text(self.event.emblem.get_display_name())
+                            .show_ui(ui, |ui| {
+                                for value in EmblemMode::iter() {
+                                    ui.selectable_value(&mut self.event.emblem, value, value.get_display_name());
+                                }
+                            });
+                        ui.end_row();
+
+                        let heading = ui.label("Heading");
+                        ui.text_edit_singleline(&mut self.event.heading_template).labelled_by(heading.id);
+                        ui.end_row();
+
+                        let subheading = ui.label("Subheading");
+                        ui.text_edit_singleline(&mut self.event.subheading_template).labelled_by(subheading.id);
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.small("Placeholders: {mat} {fight} {age} {gender} {belt} {weight} {round}");
+                        ui.end_row();
+
+                        let rotation = ui.label("Logo Rotation (secs, 0 = off)");
+                        ui.add(egui::DragValue::new(&mut self.event.logo_rotation_seconds).speed(0.5).clamp_range(0.0..=600.0)).labelled_by(rotation.id);
+                        ui.end_row();
+
+                        for (index, logo) in self.event.logos.iter_mut().enumerate() {
+                            let label = ui.label(format!("Logo {}", index + 1));
+                            ui.horizontal(|ui| {
+                                ui.add(egui::TextEdit::singleline(logo).hint_text("sponsor.png")).labelled_by(label.id);
+                                if ui.add(egui::Button::new("Remove")).clicked() {
+                                    remove = Some(index);
+                                }
+                            });
+                            ui.end_row();
+                        }
+
+                        if ui.add(egui::Button::new("Add Logo")).clicked() {
+                            self.event.logos.push(String::new());
+                        }
+                        if ui.add(egui::Button::new("Save")).clicked() {
+                            save = true;
+                        }
+                        ui.end_row();
+                    });
+            });
+
+        if let Some(index) = remove {
+            self.event.logos.remove(index);
+        }
+        if save {
+            self.logos.reload();
+            if let Err(e) = self.event.save() {
+                println!("Error saving event settings: {:#}", e);
+            }
+        }
+    }
+
+    fn draw_match_info_dialog(heading: &str, info: &mut MatchInformation, started: bool, ui: &mut egui::Ui) {
         ui.heading(heading);
         ui.end_row();
 
         let match_time = ui.label("Match Duration (mins)");
-        ui.add(egui::DragValue::new(&mut info.match_time_minutes).speed(0.1).clamp_range(1..=30)).labelled_by(match_time.id);
+        ui.add_enabled(!started, egui::DragValue::new(&mut info.match_time_minutes).speed(0.1).clamp_range(1..=30))
+            .on_disabled_hover_text(LOCKED_SETTING_HINT)
+            .labelled_by(match_time.id);
         ui.end_row();
 
         let mat_num = ui.label("Mat Number");
@@ -364,9 +1325,90 @@ impl BjjScoreboard {
         let fight_num = ui.label("Fight Number");
         ui.add(egui::DragValue::new(&mut info.fight_number).speed(0.1).clamp_range(1..=30)).labelled_by(fight_num.id);
         ui.end_row();
+
+        let age_class = ui.label("Age Class");
+        ui.add_enabled(!started, egui::TextEdit::singleline(&mut info.division.age_class).hint_text("Adult"))
+            .on_disabled_hover_text(LOCKED_SETTING_HINT)
+            .labelled_by(age_class.id);
+        ui.end_row();
+
+        let gender = ui.label("Gender");
+        ui.add_enabled_ui(!started, |ui| {
+            egui::ComboBox::from_id_source(gender.id)
+                .selected_text(format!("{:?}", info.division.gender))
+                .show_ui(ui, |ui| {
+                    for value in Gender::iter() {
+                        ui.selectable_value(&mut info.division.gender, value, format!("{:?}", value));
+                    }
+                });
+        }).response.on_disabled_hover_text(LOCKED_SETTING_HINT);
+        ui.end_row();
+
+        let belt = ui.label("Belt");
+        ui.add_enabled_ui(!started, |ui| {
+            egui::ComboBox::from_id_source(belt.id)
+                .selected_text(format!("{:?}", info.division.belt))
+                .show_ui(ui, |ui| {
+                    for value in Belt::iter() {
+                        ui.selectable_value(&mut info.division.belt, value, format!("{:?}", value));
+                    }
+                });
+        }).response.on_disabled_hover_text(LOCKED_SETTING_HINT);
+        ui.end_row();
+
+        let weight_class = ui.label("Weight Class");
+        ui.add_enabled(!started, egui::TextEdit::singleline(&mut info.division.weight_class).hint_text("-76kg"))
+            .on_disabled_hover_text(LOCKED_SETTING_HINT)
+            .labelled_by(weight_class.id);
+        ui.end_row();
+
+        let round = ui.label("Round");
+        ui.add_enabled(!started, egui::TextEdit::singleline(&mut info.round).hint_text("Semi-final"))
+            .on_disabled_hover_text(LOCKED_SETTING_HINT)
+            .labelled_by(round.id);
+        ui.end_row();
+
+        let ruleset = ui.label("Ruleset");
+        ui.add_enabled_ui(!started, |ui| {
+            egui::ComboBox::from_id_source(ruleset.id)
+                .selected_text(info.ruleset.get_display_name())
+                .show_ui(ui, |ui| {
+                    for value in RulesetKind::iter() {
+                        ui.selectable_value(&mut info.ruleset, value, value.get_display_name());
+                    }
+                });
+        }).response.on_disabled_hover_text(LOCKED_SETTING_HINT);
+        ui.end_row();
+
+        let overtime = ui.label("Overtime");
+        ui.add_enabled_ui(!started, |ui| {
+            egui::ComboBox::from_id_source(overtime.id)
+                .selected_text(info.overtime.mode.get_display_name())
+                .show_ui(ui, |ui| {
+                    for value in OvertimeMode::iter() {
+                        ui.selectable_value(&mut info.overtime.mode, value, value.get_display_name());
+                    }
+                });
+        }).response.on_disabled_hover_text(LOCKED_SETTING_HINT);
+        ui.end_row();
+
+        let has_overtime = !started && info.overtime.mode != OvertimeMode::None;
+        let overtime_minutes = ui.label("Overtime Duration (mins)");
+        ui.add_enabled(has_overtime, egui::DragValue::new(&mut info.overtime.period_minutes).speed(0.1).clamp_range(1..=10))
+            .on_disabled_hover_text(LOCKED_SETTING_HINT)
+            .labelled_by(overtime_minutes.id);
+        ui.end_row();
+
+        let overtime_periods = ui.label("Overtime Periods");
+        ui.add_enabled(has_overtime, egui::DragValue::new(&mut info.overtime.max_periods).speed(0.1).clamp_range(1..=5))
+            .on_disabled_hover_text(LOCKED_SETTING_HINT)
+            .labelled_by(overtime_periods.id);
+        ui.end_row();
     }
 
     fn draw_new_match_modal(&mut self, ctx: &egui::Context) {
+        let mut close = false;
+
         egui::Window::new("Match Settings")
             .open(&mut self.match_dialog_open)
             .show(ctx,|ui| {
@@ -388,19 +1430,14 @@ impl BjjScoreboard {
                         ui.end_row();
 
                         let team = ui.label("Team");
-                        ui.text_edit_singleline(&mut competitor.team_name).labelled_by(team.id);
+                        ui.horizontal(|ui| {
+                            ui.text_edit_singleline(&mut competitor.team_name).labelled_by(team.id);
+                            BjjScoreboard::draw_team_selector(ui, "competitor_one_team", &mut competitor.team_name, &self.teams);
+                        });
                         ui.end_row();
 
                         let country = ui.label("Country");
-                        egui::ComboBox::from_id_source(country.id)
-                            .selected_text(format!("{:?}", competitor.country))
-                            .show_ui(ui, |ui| {
-                                ui.style_mut().wrap = Some(false);
-                                ui.set_min_width(60.0);
-                                for value in self.flags.values() {
-                                    ui.selectable_value(&mut competitor.country, value.country, value.name.as_str());
-                                }
-                            });
+                        country_picker(ui, "competitor_one_country", &mut competitor.country, &mut self.flags).labelled_by(country.id);
                         ui.end_row();
                         ui.separator();
 
@@ -417,32 +1454,42 @@ impl BjjScoreboard {
                         ui.end_row();
 
                         let team = ui.label("Team");
-                        ui.text_edit_singleline(&mut competitor.team_name).labelled_by(team.id);
+                        ui.horizontal(|ui| {
+                            ui.text_edit_singleline(&mut competitor.team_name).labelled_by(team.id);
+                            BjjScoreboard::draw_team_selector(ui, "competitor_two_team", &mut competitor.team_name, &self.teams);
+                        });
                         ui.end_row();
 
                         let country = ui.label("Country");
-                        egui::ComboBox::from_id_source(country.id)
-                            .selected_text(format!("{:?}", competitor.country))
-                            .show_ui(ui, |ui| {
-                                ui.style_mut().wrap = Some(false);
-                                ui.set_min_width(60.0);
-                                for value in self.flags.values() {
-                                    ui.selectable_value(&mut competitor.country, value.country, value.name.as_str());
-                                }
-                            });
+                        country_picker(ui, "competitor_two_country", &mut competitor.country, &mut self.flags).labelled_by(country.id);
                         ui.end_row();
 
                         ui.separator();
                         ui.end_row();
-                        BjjScoreboard::draw_match_info_dialog("Match Information", &mut self.bjj_match.info, ui);
+                        let started = self.bjj_match.time.started;
+                        BjjScoreboard::draw_match_info_dialog("Match Information", &mut self.bjj_match.info, started, ui);
                         ui.separator();
                         ui.end_row();
-                        if ui.add(egui::Button::new("Start Match")).clicked() {
-                            self.app_state = AppState::Ready;
+                        match self.app_state {
+                            AppState::NewMatchDialog => {
+                                if ui.add(egui::Button::new("Start Match")).clicked() {
+                                    self.app_state = AppState::Ready;
+                                    close = true;
+                                }
+                            },
+                            _ => {
+                                if ui.add(egui::Button::new("Done")).clicked() {
+                                    close = true;
+                                }
+                            }
                         }
                     });
             }
             );
+
+        if close {
+            self.match_dialog_open = false;
+        }
     }
 
 }
@@ -1,12 +1,19 @@
 use bjj_scoreboard::BjjScoreboard;
 
 fn main() -> Result<(), eframe::Error> {
+    if std::env::args().any(|arg| arg == "--tui") {
+        if let Err(e) = bjj_scoreboard::tui::run() {
+            println!("Error running terminal scoreboard: {:#}", e);
+        }
+        return Ok(());
+    }
+
     let options = eframe::NativeOptions {
         ..Default::default()
     };
     eframe::run_native(
-        "BJJ Scoreboard",
+        "BJJ Scoreboard - Operator Console",
         options,
         Box::new(|_cc| Box::<BjjScoreboard>::default())
     )
-}
\ No newline at end of file
+}
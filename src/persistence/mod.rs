@@ -0,0 +1,174 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use anyhow::Context;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use crate::bjj_match::BJJMatch;
+
+const RUNNING_SAVE_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct MatchStore {
+    path: Option<PathBuf>,
+    last_saved: Option<String>,
+    last_write: Option<Instant>,
+    last_changes: Option<(usize, usize, bool, bool)>,
+}
+
+impl Default for MatchStore {
+    fn default() -> Self {
+        Self {
+            path: dirs::data_local_dir().map(|dir| dir.join("bjj_scoreboard").join("current_match.json")),
+            last_saved: None,
+            last_write: None,
+            last_changes: None,
+        }
+    }
+}
+
+impl MatchStore {
+    pub fn load(&self) -> Option<BJJMatch> {
+        let path = self.path.as_ref()?;
+        let contents = fs::read_to_string(path).ok()?;
+
+        match serde_json::from_str(&contents) {
+            Ok(bjj_match) => Some(bjj_match),
+            Err(e) => {
+                println!("Error reading saved match: {}", e);
+                None
+            }
+        }
+    }
+
+    pub fn save(&mut self, bjj_match: &BJJMatch) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let changes = (bjj_match.log.events.len(), bjj_match.log.undone.len(), bjj_match.result.is_some(), bjj_match.referee_decision_required);
+        let ticking = bjj_match.time.running || bjj_match.stoppages.running().next().is_some();
+        if ticking && self.last_changes == Some(changes) {
+            if let Some(last_write) = self.last_write {
+                if last_write.elapsed() < RUNNING_SAVE_INTERVAL {
+                    return Ok(());
+                }
+            }
+        }
+
+        let mut snapshot = bjj_match.clone();
+        snapshot.time.stop();
+        for timer in &mut snapshot.stoppages.timers {
+            timer.time.stop();
+        }
+        let contents = serde_json::to_string(&snapshot)?;
+
+        if self.last_saved.as_ref() == Some(&contents) {
+            return Ok(());
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Unable to create {}", dir.display()))?;
+        }
+
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, &contents).with_context(|| format!("Unable to write {}", temp_path.display()))?;
+        fs::rename(&temp_path, path).with_context(|| format!("Unable to write {}", path.display()))?;
+
+        self.last_saved = Some(contents);
+        self.last_write = Some(Instant::now());
+        self.last_changes = Some(changes);
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        if let Some(path) = &self.path {
+            let _ = fs::remove_file(path);
+        }
+        self.last_saved = None;
+        self.last_write = None;
+        self.last_changes = None;
+    }
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("bjj_scoreboard"))
+}
+
+pub fn load_config<T: DeserializeOwned + Default>(file_name: &str) -> T {
+    let Some(path) = config_dir().map(|dir| dir.join(file_name)) else {
+        return T::default();
+    };
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return T::default();
+    };
+
+    match serde_json::from_str(&contents) {
+        Ok(config) => config,
+        Err(e) => {
+            println!("Error reading {}: {}", path.display(), e);
+            T::default()
+        }
+    }
+}
+
+pub fn save_config<T: Serialize>(file_name: &str, config: &T) -> anyhow::Result<()> {
+    let dir = config_dir().context("No configuration directory available")?;
+    fs::create_dir_all(&dir).with_context(|| format!("Unable to create {}", dir.display()))?;
+
+    let path = dir.join(file_name);
+    let contents = serde_json::to_string_pretty(config)?;
+    fs::write(&path, contents).with_context(|| format!("Unable to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::*;
+    use crate::bjj_match::competitor::{Competitor, CompetitorNumber};
+    use crate::bjj_match::match_time::{ManualClock, MatchTime};
+    use crate::bjj_match::ruleset::RulesetKind;
+    use crate::bjj_match::stoppage::StoppageKind;
+
+    fn temp_store(name: &str) -> MatchStore {
+        MatchStore {
+            path: Some(std::env::temp_dir().join(format!("bjj_scoreboard_{}_{}.json", name, std::process::id()))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn saves_score_changes_while_the_clock_is_running() {
+        let mut store = temp_store("running");
+        let mut bjj_match = BJJMatch::new(Competitor::default(), Competitor::default(), 5, 1, 1, RulesetKind::Ibjjf);
+        bjj_match.start();
+        store.save(&bjj_match).unwrap();
+
+        bjj_match.add_points(2, CompetitorNumber::One);
+        store.save(&bjj_match).unwrap();
+        assert_eq!(store.load().unwrap().get_raw_score().competitor_one_score.points, 2);
+
+        bjj_match.undo();
+        store.save(&bjj_match).unwrap();
+        assert_eq!(store.load().unwrap().get_raw_score().competitor_one_score.points, 0);
+
+        store.clear();
+    }
+
+    #[test]
+    fn saves_running_stoppages_as_stopped() {
+        let clock = Arc::new(ManualClock::default());
+        let mut bjj_match = BJJMatch { time: MatchTime::with_clock(60_000, clock.clone()), ..Default::default() };
+        bjj_match.stoppages.get_or_insert(CompetitorNumber::One, StoppageKind::Injury, clock.clone()).time.start();
+        clock.advance(5_000);
+
+        let mut store = temp_store("stoppages");
+        store.save(&bjj_match).unwrap();
+        let restored = store.load().unwrap();
+        store.clear();
+
+        let timer = restored.stoppages.get(CompetitorNumber::One, StoppageKind::Injury).unwrap();
+        assert!(!timer.time.running);
+        assert_eq!(timer.time.get_elapsed_time_milliseconds(), 5_000);
+        assert!(bjj_match.stoppages.is_running(CompetitorNumber::One, StoppageKind::Injury));
+    }
+}
@@ -0,0 +1,235 @@
+use std::io::ErrorKind;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tungstenite::{Error, Message, WebSocket};
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use crate::bjj_match::{BJJMatch, MatchState};
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::match_information::MatchInformation;
+use crate::bjj_match::match_result::MatchResult;
+use crate::bjj_match::match_score::MatchScore;
+use crate::bjj_match::stoppage::StoppageKind;
+use crate::server::SharedMatch;
+
+pub const DEFAULT_TICK_MILLIS: u64 = 250;
+const MIN_TICK_MILLIS: u64 = 20;
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const POLL_TIMEOUT: Duration = Duration::from_millis(10);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+const PING_INTERVAL: Duration = Duration::from_secs(2);
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+pub struct LiveSnapshot<'a> {
+    pub info: &'a MatchInformation,
+    pub score: MatchScore,
+    pub remaining_time_millis: usize,
+    pub remaining_time: String,
+    pub running: bool,
+    pub period: Option<String>,
+    pub state: MatchState,
+    pub result: &'a Option<MatchResult>,
+    pub result_method: Option<&'static str>,
+    pub flag_codes: [String; 2],
+    pub stoppages: Vec<LiveStoppage>,
+}
+
+#[derive(Serialize)]
+pub struct LiveStoppage {
+    pub competitor: CompetitorNumber,
+    pub kind: StoppageKind,
+    pub remaining_time: String,
+}
+
+impl<'a> LiveSnapshot<'a> {
+    pub fn new(bjj_match: &'a BJJMatch) -> LiveSnapshot<'a> {
+        LiveSnapshot {
+            info: &bjj_match.info,
+            score: bjj_match.get_score(),
+            remaining_time_millis: bjj_match.time.get_remaining_time_milliseconds(),
+            remaining_time: bjj_match.time.get_remaining_time_string(),
+            running: bjj_match.time.running,
+            period: bjj_match.get_period_name(),
+            state: bjj_match.get_match_state(),
+            result: &bjj_match.result,
+            result_method: bjj_match.result.as_ref().map(|result| result.method.get_display_name()),
+            flag_codes: [
+                bjj_match.info.competitor_one.country.get_code().to_owned(),
+                bjj_match.info.competitor_two.country.get_code().to_owned()
+            ],
+            stoppages: bjj_match.stoppages.running()
+                .map(|timer| LiveStoppage {
+                    competitor: timer.competitor,
+                    kind: timer.kind,
+                    remaining_time: timer.time.get_remaining_time_string()
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn listen(listener: TcpListener, shared: SharedMatch) {
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    let shared = shared.clone();
+                    thread::spawn(move || serve(stream, shared, CLIENT_TIMEOUT));
+                },
+                Err(e) => println!("Error accepting live client: {}", e)
+            }
+        }
+    });
+}
+
+#[allow(clippy::result_large_err)]
+fn serve(stream: TcpStream, shared: SharedMatch, client_timeout: Duration) {
+    if stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).is_err() || stream.set_write_timeout(Some(WRITE_TIMEOUT)).is_err() {
+        return;
+    }
+
+    let mut tick = Duration::from_millis(DEFAULT_TICK_MILLIS);
+    let handshake = tungstenite::accept_hdr(stream, |request: &Request, response: Response| {
+        if request.uri().path() != "/live" {
+            let mut error = ErrorResponse::new(Some("Not found".to_owned()));
+            *error.status_mut() = StatusCode::NOT_FOUND;
+            return Err(error);
+        }
+        tick = Duration::from_millis(parse_tick_millis(request.uri().query()));
+        Ok(response)
+    });
+    let mut socket = match handshake {
+        Ok(socket) => socket,
+        Err(_) => return
+    };
+    if socket.get_ref().set_read_timeout(Some(POLL_TIMEOUT)).is_err() {
+        return;
+    }
+
+    let mut last_version = None;
+    let mut last_heard = Instant::now();
+    let mut last_ping = Instant::now();
+
+    loop {
+        let snapshot = {
+            let (lock, changed) = &*shared;
+            let Ok(mut published) = lock.lock() else {
+                return;
+            };
+            if last_version == Some(published.version) {
+                published = match changed.wait_timeout(published, tick) {
+                    Ok((published, _)) => published,
+                    Err(_) => return
+                };
+            }
+            last_version = Some(published.version);
+            serde_json::to_string(&LiveSnapshot::new(&published.bjj_match))
+        };
+
+        match snapshot {
+            Ok(snapshot) => {
+                if socket.send(Message::Text(snapshot)).is_err() {
+                    return;
+                }
+            },
+            Err(e) => {
+                println!("Error serializing live snapshot: {}", e);
+                return;
+            }
+        }
+
+        if last_ping.elapsed() >= PING_INTERVAL {
+            if socket.send(Message::Ping(Vec::new())).is_err() {
+                return;
+            }
+            last_ping = Instant::now();
+        }
+
+        if !poll_client(&mut socket, &mut last_heard) || last_heard.elapsed() > client_timeout {
+            return;
+        }
+    }
+}
+
+fn poll_client(socket: &mut WebSocket<TcpStream>, last_heard: &mut Instant) -> bool {
+    loop {
+        match socket.read() {
+            Ok(_) => *last_heard = Instant::now(),
+            Err(Error::Io(e)) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => return true,
+            Err(_) => return false
+        }
+    }
+}
+
+fn parse_tick_millis(query: Option<&str>) -> u64 {
+    query
+        .and_then(|query| query.split('&').find_map(|pair| pair.strip_prefix("tick=")))
+        .and_then(|tick| tick.parse().ok())
+        .unwrap_or(DEFAULT_TICK_MILLIS)
+        .max(MIN_TICK_MILLIS)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Condvar, Mutex};
+    use super::*;
+    use crate::server::PublishedMatch;
+
+    fn shared_match() -> SharedMatch {
+        Arc::new((
+            Mutex::new(PublishedMatch { bjj_match: BJJMatch::default(), version: 0 }),
+            Condvar::new()
+        ))
+    }
+
+    fn connect(client_timeout: Duration) -> (WebSocket<TcpStream>, thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let shared = shared_match();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            serve(stream, shared, client_timeout);
+        });
+
+        let stream = TcpStream::connect(address).unwrap();
+        let (socket, _) = tungstenite::client(format!("ws://{}/live?tick=50", address), stream).unwrap();
+        (socket, server)
+    }
+
+    #[test]
+    fn parses_tick_from_query() {
+        assert_eq!(parse_tick_millis(None), DEFAULT_TICK_MILLIS);
+        assert_eq!(parse_tick_millis(Some("tick=100")), 100);
+        assert_eq!(parse_tick_millis(Some("other=1&tick=5")), MIN_TICK_MILLIS);
+        assert_eq!(parse_tick_millis(Some("tick=soon")), DEFAULT_TICK_MILLIS);
+    }
+
+    #[test]
+    fn sends_snapshots_until_the_client_closes() {
+        let (mut socket, server) = connect(CLIENT_TIMEOUT);
+
+        let snapshot: serde_json::Value = match socket.read().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            message => panic!("Unexpected message {:?}", message)
+        };
+        assert_eq!(snapshot["state"], "NotStarted");
+        assert_eq!(snapshot["stoppages"], serde_json::json!([]));
+        assert_eq!(snapshot["period"], serde_json::Value::Null);
+
+        socket.close(None).unwrap();
+        while socket.read().is_ok() {}
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn drops_unresponsive_clients() {
+        let (_socket, server) = connect(Duration::from_millis(200));
+        let started = Instant::now();
+
+        server.join().unwrap();
+        assert!(started.elapsed() < CLIENT_TIMEOUT);
+    }
+}
@@ -0,0 +1,460 @@
+pub mod live;
+pub mod overlay;
+
+use std::io::Read;
+use std::net::TcpListener;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use anyhow::anyhow;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use tiny_http::{Header, Method, Request, Response, Server};
+use crate::action::Action;
+use crate::bjj_match::{BJJMatch, MatchState};
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::match_result::WinMethod;
+use crate::bjj_match::match_score::MatchScore;
+use crate::bjj_match::match_time::MatchTime;
+use crate::bjj_match::stoppage::StoppageKind;
+use crate::bjj_match::ebi::{EbiOutcome, EbiPosition};
+use crate::flags::Country;
+use crate::fonts;
+use crate::ui::ColorScheme;
+
+const MAX_BODY_BYTES: u64 = 64 * 1024;
+const MAX_CLOCK_SECONDS: usize = 60 * 60;
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:7878";
+pub const ADDRESS_ENV_VAR: &str = "BJJ_SCOREBOARD_API_ADDRESS";
+pub const DEFAULT_LIVE_ADDRESS: &str = "127.0.0.1:7879";
+pub const LIVE_ADDRESS_ENV_VAR: &str = "BJJ_SCOREBOARD_LIVE_ADDRESS";
+
+#[derive(Serialize)]
+struct MatchStatus<'a> {
+    #[serde(flatten)]
+    bjj_match: &'a BJJMatch,
+    state: MatchState,
+    display_score: MatchScore,
+    remaining_time_millis: usize,
+}
+
+#[derive(Deserialize)]
+struct ResultRequest {
+    winner: CompetitorNumber,
+    method: WinMethod,
+}
+
+pub struct PublishedMatch {
+    pub bjj_match: BJJMatch,
+    pub version: u64,
+}
+
+pub type SharedMatch = Arc<(Mutex<PublishedMatch>, Condvar)>;
+
+struct ServerContext {
+    shared: SharedMatch,
+    overlay: Arc<Mutex<String>>,
+    main_font: Arc<Mutex<Vec<u8>>>,
+    sender: Sender<Action>,
+    ctx: egui::Context,
+}
+
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    fn json(status: u16, body: String) -> Reply {
+        Reply { status, content_type: "application/json", body: body.into_bytes() }
+    }
+}
+
+pub struct ApiServer {
+    shared: SharedMatch,
+    overlay: Arc<Mutex<String>>,
+    main_font: Arc<Mutex<Vec<u8>>>,
+    actions: Receiver<Action>,
+    last_published: String,
+    pub live_port: u16,
+}
+
+impl ApiServer {
+    pub fn start(address: &str, live_address: &str, ctx: egui::Context, color_scheme: &ColorScheme) -> anyhow::Result<ApiServer> {
+        let server = Server::http(address).map_err(|e| anyhow!("Unable to listen on {}: {}", address, e))?;
+        let live_listener = TcpListener::bind(live_address).map_err(|e| anyhow!("Unable to listen on {}: {}", live_address, e))?;
+        let live_port = live_listener.local_addr()?.port();
+        let shared: SharedMatch = Arc::new((
+            Mutex::new(PublishedMatch { bjj_match: BJJMatch::default(), version: 0 }),
+            Condvar::new()
+        ));
+        let overlay = Arc::new(Mutex::new(overlay::render(color_scheme, live_port)));
+        let main_font = Arc::new(Mutex::new(fonts::MAIN_FONT.to_vec()));
+        let (sender, actions) = mpsc::channel();
+
+        live::listen(live_listener, shared.clone());
+
+        let server_context = ServerContext {
+            shared: shared.clone(),
+            overlay: overlay.clone(),
+            main_font: main_font.clone(),
+            sender,
+            ctx,
+        };
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                handle_request(request, &server_context);
+            }
+        });
+
+        Ok(ApiServer {
+            shared,
+            overlay,
+            main_font,
+            actions,
+            last_published: String::new(),
+            live_port,
+        })
+    }
+
+    pub fn set_color_scheme(&self, color_scheme: &ColorScheme) {
+        if let Ok(mut overlay) = self.overlay.lock() {
+            *overlay = overlay::render(color_scheme, self.live_port);
+        }
+    }
+
+    pub fn set_main_font(&self, font: Vec<u8>) {
+        if let Ok(mut main_font) = self.main_font.lock() {
+            *main_font = font;
+        }
+    }
+
+    pub fn address() -> String {
+        std::env::var(ADDRESS_ENV_VAR).unwrap_or_else(|_| DEFAULT_ADDRESS.to_owned())
+    }
+
+    pub fn live_address() -> String {
+        std::env::var(LIVE_ADDRESS_ENV_VAR).unwrap_or_else(|_| DEFAULT_LIVE_ADDRESS.to_owned())
+    }
+
+    pub fn publish(&mut self, bjj_match: &BJJMatch) {
+        let Ok(serialized) = serde_json::to_string(bjj_match) else {
+            return;
+        };
+        if serialized == self.last_published {
+            return;
+        }
+
+        let (lock, changed) = &*self.shared;
+        if let Ok(mut published) = lock.lock() {
+            published.bjj_match = bjj_match.clone();
+            published.version += 1;
+            self.last_published = serialized;
+            changed.notify_all();
+        }
+    }
+
+    pub fn take_actions(&self) -> Vec<Action> {
+        self.actions.try_iter().collect()
+    }
+}
+
+fn handle_request(mut request: Request, server: &ServerContext) {
+    let path = request.url().split('?').next().unwrap_or_default().to_owned();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    let method = request.method().clone();
+
+    let reply = if method == Method::Get {
+        route(&method, &segments, &mut std::io::empty(), server)
+    } else if is_cross_origin(header(&request, "Origin"), header(&request, "Host")) {
+        error(403, "Cross-origin requests can't change the match")
+    } else if request.body_length().is_some_and(|length| length as u64 > MAX_BODY_BYTES) {
+        error(413, "Request body is too large")
+    } else {
+        route(&method, &segments, &mut request.as_reader().take(MAX_BODY_BYTES + 1), server)
+    };
+
+    let mut response = Response::from_data(reply.body)
+        .with_status_code(reply.status)
+        .with_header(Header::from_bytes("Content-Type", reply.content_type).unwrap());
+    if method == Method::Get {
+        response.add_header(Header::from_bytes("Access-Control-Allow-Origin", "*").unwrap());
+    }
+
+    if let Err(e) = request.respond(response) {
+        println!("Error responding to API request: {}", e);
+    }
+}
+
+fn header<'a>(request: &'a Request, name: &'static str) -> Option<&'a str> {
+    request.headers().iter()
+        .find(|header| header.field.equiv(name))
+        .map(|header| header.value.as_str())
+}
+
+fn is_cross_origin(origin: Option<&str>, host: Option<&str>) -> bool {
+    match origin {
+        Some(origin) => {
+            let origin_host = origin.strip_prefix("http://").or_else(|| origin.strip_prefix("https://"));
+            origin_host.is_none() || origin_host != host
+        },
+        None => false
+    }
+}
+
+fn route(method: &Method, segments: &[&str], body: &mut dyn Read, server: &ServerContext) -> Reply {
+    match (method, segments) {
+        (Method::Get, ["match"]) => match server.shared.0.lock() {
+            Ok(published) => match_status(&published.bjj_match),
+            Err(_) => error(500, "Match state unavailable")
+        },
+        (Method::Get, ["overlay"]) => match server.overlay.lock() {
+            Ok(overlay) => Reply { status: 200, content_type: "text/html; charset=utf-8", body: overlay.clone().into_bytes() },
+            Err(_) => error(500, "Overlay unavailable")
+        },
+        (Method::Get, ["flags", file]) => {
+            match file.strip_suffix(".svg").and_then(Country::from_code) {
+                Some(country) => Reply { status: 200, content_type: "image/svg+xml", body: country.get_svg_bytes().to_vec() },
+                None => error(404, "Unknown flag")
+            }
+        },
+        (Method::Get, ["fonts", "main.ttf"]) => match server.main_font.lock() {
+            Ok(main_font) => Reply { status: 200, content_type: "font/ttf", body: main_font.clone() },
+            Err(_) => error(500, "Font unavailable")
+        },
+        (Method::Post, ["match", "result"]) => {
+            let mut content = String::new();
+            match body.read_to_string(&mut content) {
+                Ok(_) if content.len() as u64 > MAX_BODY_BYTES => error(413, "Request body is too large"),
+                Ok(_) => match serde_json::from_str::<ResultRequest>(&content) {
+                    Ok(result) if !result.method.is_manual() => error(400, &format!("{} can't be recorded manually", result.method.get_name())),
+                    Ok(result) => queue_action(Action::Win(result.winner, result.method), server),
+                    Err(e) => error(400, &e.to_string())
+                },
+                Err(e) => error(400, &e.to_string())
+            }
+        },
+        (Method::Post, segments) => match parse_action(segments) {
+            Some(action) => queue_action(action, server),
+            None => error(404, "Unknown action")
+        },
+        _ => error(404, "Not found")
+    }
+}
+
+fn match_status(bjj_match: &BJJMatch) -> Reply {
+    let status = MatchStatus {
+        bjj_match,
+        state: bjj_match.get_match_state(),
+        display_score: bjj_match.get_score(),
+        remaining_time_millis: bjj_match.time.get_remaining_time_milliseconds(),
+    };
+
+    match serde_json::to_string(&status) {
+        Ok(json) => Reply::json(200, json),
+        Err(e) => error(500, &e.to_string())
+    }
+}
+
+fn queue_action(action: Action, server: &ServerContext) -> Reply {
+    match server.sender.send(action) {
+        Ok(_) => {
+            server.ctx.request_repaint();
+            Reply::json(202, serde_json::json!({ "queued": action }).to_string())
+        },
+        Err(_) => error(503, "Scoreboard is not running")
+    }
+}
+
+fn error(status: u16, message: &str) -> Reply {
+    Reply::json(status, serde_json::json!({ "error": message }).to_string())
+}
+
+fn parse_competitor(segment: &str) -> Option<CompetitorNumber> {
+    match segment {
+        "one" => Some(CompetitorNumber::One),
+        "two" => Some(CompetitorNumber::Two),
+        _ => None
+    }
+}
+
+fn parse_action(segments: &[&str]) -> Option<Action> {
+    match segments {
+        ["match", "start-stop"] => Some(Action::StartStop),
+        ["match", "new"] => Some(Action::NewMatch),
+        ["match", "undo"] => Some(Action::Undo),
+        ["match", "redo"] => Some(Action::Redo),
+        ["clock", "adjust", seconds] => seconds.parse::<isize>().ok()
+            .filter(|seconds| seconds.unsigned_abs() <= MAX_CLOCK_SECONDS)
+            .map(Action::AdjustClock),
+        ["clock", "remaining", remaining] => MatchTime::parse_millis(remaining)
+            .filter(|millis| *millis <= MAX_CLOCK_SECONDS * 1000)
+            .map(Action::SetRemainingTime),
+        ["ebi", "position", position] => EbiPosition::iter()
+            .find(|value| value.get_name() == *position)
+            .map(Action::SetEbiPosition),
+        ["ebi", outcome] => [EbiOutcome::Submission, EbiOutcome::Escape].into_iter()
+            .find(|value| value.get_name() == *outcome)
+            .map(Action::RecordEbiOutcome),
+        ["competitor", competitor, rest @ ..] => {
+            let competitor = parse_competitor(competitor)?;
+            match rest {
+                ["points", "subtract"] => Some(Action::SubtractPoint(competitor)),
+                ["points", points] => match points.parse() {
+                    Ok(points @ 2..=4) => Some(Action::AddPoints(competitor, points)),
+                    _ => None
+                },
+                ["advantage"] => Some(Action::AddAdvantage(competitor)),
+                ["advantage", "subtract"] => Some(Action::SubtractAdvantage(competitor)),
+                ["penalty"] => Some(Action::AddPenalty(competitor)),
+                ["penalty", "subtract"] => Some(Action::SubtractPenalty(competitor)),
+                ["stoppage", kind] => StoppageKind::iter()
+                    .find(|stoppage| stoppage.get_name() == *kind)
+                    .map(|stoppage| Action::ToggleStoppage(competitor, stoppage)),
+                _ => None
+            }
+        },
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_context() -> (ServerContext, Receiver<Action>) {
+        let (sender, actions) = mpsc::channel();
+        let server = ServerContext {
+            shared: Arc::new((
+                Mutex::new(PublishedMatch { bjj_match: BJJMatch::default(), version: 0 }),
+                Condvar::new()
+            )),
+            overlay: Arc::new(Mutex::new(String::from("<html></html>"))),
+            main_font: Arc::new(Mutex::new(fonts::MAIN_FONT.to_vec())),
+            sender,
+            ctx: egui::Context::default(),
+        };
+        (server, actions)
+    }
+
+    fn get(path: &[&str], server: &ServerContext) -> Reply {
+        route(&Method::Get, path, &mut "".as_bytes(), server)
+    }
+
+    fn post(path: &[&str], body: &str, server: &ServerContext) -> Reply {
+        route(&Method::Post, path, &mut body.as_bytes(), server)
+    }
+
+    #[test]
+    fn parses_match_routes() {
+        assert_eq!(parse_action(&["match", "start-stop"]), Some(Action::StartStop));
+        assert_eq!(parse_action(&["match", "new"]), Some(Action::NewMatch));
+        assert_eq!(parse_action(&["match", "undo"]), Some(Action::Undo));
+        assert_eq!(parse_action(&["match", "redo"]), Some(Action::Redo));
+        assert_eq!(parse_action(&["match", "explode"]), None);
+        assert_eq!(parse_action(&["clock", "adjust", "-10"]), Some(Action::AdjustClock(-10)));
+        assert_eq!(parse_action(&["clock", "remaining", "1:30"]), Some(Action::SetRemainingTime(90_000)));
+        assert_eq!(parse_action(&["clock", "adjust", "9223372036854775807"]), None);
+        assert_eq!(parse_action(&["clock", "adjust", "-9223372036854775808"]), None);
+        assert_eq!(parse_action(&["clock", "remaining", "61:00"]), None);
+        assert_eq!(parse_action(&["clock", "remaining", "18446744073709551615:00"]), None);
+        assert_eq!(parse_action(&["clock", "adjust", "soon"]), None);
+    }
+
+    #[test]
+    fn parses_competitor_routes() {
+        let two = CompetitorNumber::Two;
+        assert_eq!(parse_action(&["competitor", "one", "points", "3"]), Some(Action::AddPoints(CompetitorNumber::One, 3)));
+        assert_eq!(parse_action(&["competitor", "two", "points", "subtract"]), Some(Action::SubtractPoint(two)));
+        assert_eq!(parse_action(&["competitor", "two", "advantage"]), Some(Action::AddAdvantage(two)));
+        assert_eq!(parse_action(&["competitor", "two", "advantage", "subtract"]), Some(Action::SubtractAdvantage(two)));
+        assert_eq!(parse_action(&["competitor", "two", "penalty"]), Some(Action::AddPenalty(two)));
+        assert_eq!(parse_action(&["competitor", "two", "penalty", "subtract"]), Some(Action::SubtractPenalty(two)));
+        assert_eq!(parse_action(&["competitor", "one", "points", "5"]), None);
+        assert_eq!(parse_action(&["competitor", "three", "advantage"]), None);
+    }
+
+    #[test]
+    fn queues_posted_actions() {
+        let (server, actions) = server_context();
+
+        assert_eq!(post(&["competitor", "one", "advantage"], "", &server).status, 202);
+        assert_eq!(actions.try_recv(), Ok(Action::AddAdvantage(CompetitorNumber::One)));
+
+        assert_eq!(post(&["match", "result"], r#"{"winner":"Two","method":"Points"}"#, &server).status, 202);
+        assert_eq!(actions.try_recv(), Ok(Action::Win(CompetitorNumber::Two, WinMethod::Points)));
+    }
+
+    #[test]
+    fn serves_match_status() {
+        let (server, _actions) = server_context();
+
+        let reply = get(&["match"], &server);
+        assert_eq!(reply.status, 200);
+        let status: serde_json::Value = serde_json::from_slice(&reply.body).unwrap();
+        assert_eq!(status["state"], "NotStarted");
+    }
+
+    #[test]
+    fn serves_overlay_assets() {
+        let (server, _actions) = server_context();
+
+        let reply = get(&["overlay"], &server);
+        assert_eq!((reply.status, reply.content_type), (200, "text/html; charset=utf-8"));
+        let flag = format!("{}.svg", Country::Brazil.get_code());
+        assert_eq!(get(&["flags", &flag], &server).content_type, "image/svg+xml");
+        assert_eq!(get(&["fonts", "main.ttf"], &server).content_type, "font/ttf");
+    }
+
+    #[test]
+    fn rejects_malformed_result() {
+        let (server, actions) = server_context();
+
+        assert_eq!(post(&["match", "result"], "{", &server).status, 400);
+        assert_eq!(post(&["match", "result"], r#"{"winner":"Three","method":"Points"}"#, &server).status, 400);
+        let reply = post(&["match", "result"], r#"{"winner":"One","method":"EscapeTime"}"#, &server);
+        assert_eq!(reply.status, 400);
+        assert!(String::from_utf8_lossy(&reply.body).contains("escape_time"));
+        assert!(actions.try_recv().is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_result() {
+        let (server, actions) = server_context();
+        let body = " ".repeat(MAX_BODY_BYTES as usize + 1);
+
+        assert_eq!(route(&Method::Post, &["match", "result"], &mut body.as_bytes().take(MAX_BODY_BYTES + 1), &server).status, 413);
+        assert!(actions.try_recv().is_err());
+    }
+
+    #[test]
+    fn rejects_cross_origin_requests() {
+        assert!(!is_cross_origin(None, Some("127.0.0.1:7878")));
+        assert!(!is_cross_origin(Some("http://127.0.0.1:7878"), Some("127.0.0.1:7878")));
+        assert!(is_cross_origin(Some("http://evil.example"), Some("127.0.0.1:7878")));
+        assert!(is_cross_origin(Some("null"), Some("127.0.0.1:7878")));
+        assert!(is_cross_origin(Some("http://127.0.0.1:7878"), None));
+    }
+
+    #[test]
+    fn reports_unknown_routes() {
+        let (server, actions) = server_context();
+
+        assert_eq!(get(&["nowhere"], &server).status, 404);
+        assert_eq!(get(&["flags", "zz.svg"], &server).status, 404);
+        assert_eq!(post(&["match", "explode"], "", &server).status, 404);
+        assert_eq!(route(&Method::Delete, &["match"], &mut "".as_bytes(), &server).status, 404);
+        assert!(actions.try_recv().is_err());
+    }
+
+    #[test]
+    fn reports_stopped_scoreboard() {
+        let (server, actions) = server_context();
+        drop(actions);
+
+        assert_eq!(post(&["match", "start-stop"], "", &server).status, 503);
+    }
+}
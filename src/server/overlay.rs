@@ -0,0 +1,49 @@
+use eframe::egui::Color32;
+use crate::ui::ColorScheme;
+
+const TEMPLATE: &str = include_str!("../../assets/overlay/overlay.html");
+
+pub fn render(color_scheme: &ColorScheme, live_port: u16) -> String {
+    TEMPLATE
+        .replace("/*COLOR_SCHEME*/", &css_variables(color_scheme))
+        .replace("/*LIVE_PORT*/", &live_port.to_string())
+}
+
+fn css_variables(color_scheme: &ColorScheme) -> String {
+    let variables = [
+        ("competitor-one-bg", color_scheme.competitor_one_bg),
+        ("competitor-one-name", color_scheme.competitor_one_name),
+        ("competitor-one-team", color_scheme.competitor_one_team),
+        ("competitor-one-adv-bg", color_scheme.competitor_one_adv_bg),
+        ("competitor-one-adv", color_scheme.competitor_one_adv),
+        ("competitor-one-pen-bg", color_scheme.competitor_one_pen_bg),
+        ("competitor-one-pen", color_scheme.competitor_one_pen),
+        ("competitor-one-points-bg", color_scheme.competitor_one_points_bg),
+        ("competitor-one-points", color_scheme.competitor_one_points),
+        ("competitor-two-bg", color_scheme.competitor_two_bg),
+        ("competitor-two-name", color_scheme.competitor_two_name),
+        ("competitor-two-team", color_scheme.competitor_two_team),
+        ("competitor-two-adv-bg", color_scheme.competitor_two_adv_bg),
+        ("competitor-two-adv", color_scheme.competitor_two_adv),
+        ("competitor-two-pen-bg", color_scheme.competitor_two_pen_bg),
+        ("competitor-two-pen", color_scheme.competitor_two_pen),
+        ("competitor-two-points-bg", color_scheme.competitor_two_points_bg),
+        ("competitor-two-points", color_scheme.competitor_two_points),
+        ("bottom-pane-bg", color_scheme.bottom_pane_bg),
+        ("time", color_scheme.time),
+        ("fight-info-heading", color_scheme.fight_info_heading),
+        ("fight-info-sub-heading", color_scheme.fight_info_sub_heading),
+        ("winner-banner", color_scheme.winner_banner),
+        ("stoppage-timer", color_scheme.stoppage_timer),
+    ];
+
+    variables.iter()
+        .map(|(name, color)| format!("    --{}: {};", name, css_color(*color)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn css_color(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    format!("rgba({}, {}, {}, {:.3})", r, g, b, a as f32 / 255.0)
+}
@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::persistence;
+use crate::textures;
+
+const TEAMS_FILE: &str = "teams.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Team {
+    pub name: String,
+    pub short_code: String,
+    pub logo: String,
+}
+
+impl Team {
+    pub fn get_logo_path(&self) -> Option<PathBuf> {
+        textures::resolve_logo_path(&self.logo)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeamRegistry {
+    pub teams: Vec<Team>,
+}
+
+impl TeamRegistry {
+    pub fn load() -> TeamRegistry {
+        persistence::load_config(TEAMS_FILE)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        persistence::save_config(TEAMS_FILE, self)
+    }
+
+    pub fn find(&self, team_name: &str) -> Option<&Team> {
+        let team_name = team_name.trim();
+        self.teams.iter().find(|team| {
+            team.name.trim().eq_ignore_ascii_case(team_name)
+                || (!team.short_code.trim().is_empty() && team.short_code.trim().eq_ignore_ascii_case(team_name))
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use anyhow::{anyhow, Context};
+use eframe::egui::{self, ColorImage, TextureHandle, TextureOptions};
+use egui_extras::image::FitTo;
+use crate::persistence;
+
+pub const LOGO_HEIGHT: u32 = 360;
+
+pub struct TextureLoader<K> {
+    name: &'static str,
+    textures: BTreeMap<K, TextureHandle>,
+    requested: BTreeSet<K>,
+    requests: Option<Sender<K>>,
+    loaded: Option<Receiver<(K, Option<ColorImage>)>>,
+    ctx: Option<egui::Context>,
+}
+
+impl<K> TextureLoader<K> {
+    pub fn new(name: &'static str) -> TextureLoader<K> {
+        TextureLoader {
+            name,
+            textures: BTreeMap::new(),
+            requested: BTreeSet::new(),
+            requests: None,
+            loaded: None,
+            ctx: None,
+        }
+    }
+}
+
+impl<K: Ord + Clone + Debug + Send + 'static> TextureLoader<K> {
+    pub fn start(&mut self, ctx: &egui::Context, load: impl Fn(&K) -> anyhow::Result<ColorImage> + Send + 'static) {
+        let (requests, pending) = mpsc::channel::<K>();
+        let (finished, loaded) = mpsc::channel();
+        let repaint = ctx.clone();
+        let name = self.name;
+
+        thread::spawn(move || {
+            for key in pending {
+                let image = match load(&key) {
+                    Ok(image) => Some(image),
+                    Err(e) => {
+                        println!("Error loading {} {:?}: {:#}", name, key, e);
+                        None
+                    }
+                };
+                if finished.send((key, image)).is_err() {
+                    return;
+                }
+                repaint.request_repaint();
+            }
+        });
+
+        self.requests = Some(requests);
+        self.loaded = Some(loaded);
+        self.ctx = Some(ctx.clone());
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<TextureHandle> {
+        self.receive();
+
+        if let Some(texture) = self.textures.get(key) {
+            return Some(texture.clone());
+        }
+
+        if self.requested.insert(key.clone()) {
+            if let Some(requests) = &self.requests {
+                let _ = requests.send(key.clone());
+            }
+        }
+        None
+    }
+
+    pub fn clear(&mut self) {
+        self.receive();
+        self.textures.clear();
+        self.requested.clear();
+    }
+
+    fn receive(&mut self) {
+        let (Some(loaded), Some(ctx)) = (&self.loaded, &self.ctx) else {
+            return;
+        };
+
+        for (key, image) in loaded.try_iter() {
+            let Some(image) = image else {
+                self.requested.remove(&key);
+                continue;
+            };
+            let texture = ctx.load_texture(
+                format!("{}-{:?}", self.name, key),
+                image,
+                TextureOptions::default()
+            );
+            self.textures.insert(key, texture);
+        }
+    }
+}
+
+pub struct LogoTextures {
+    loader: TextureLoader<(PathBuf, u32)>,
+}
+
+impl Default for LogoTextures {
+    fn default() -> Self {
+        Self {
+            loader: TextureLoader::new("logo"),
+        }
+    }
+}
+
+impl LogoTextures {
+    pub fn start(&mut self, ctx: &egui::Context) {
+        self.loader.start(ctx, |(path, height)| load_image_file(path, *height));
+    }
+
+    pub fn get(&mut self, path: PathBuf, height: u32) -> Option<TextureHandle> {
+        self.loader.get(&(path, height))
+    }
+
+    pub fn reload(&mut self) {
+        self.loader.clear();
+    }
+}
+
+pub fn logo_dir() -> Option<PathBuf> {
+    persistence::config_dir().map(|dir| dir.join("logos"))
+}
+
+pub fn resolve_logo_path(logo: &str) -> Option<PathBuf> {
+    let logo = logo.trim();
+    if logo.is_empty() {
+        return None;
+    }
+
+    let path = PathBuf::from(logo);
+    match path.is_absolute() {
+        true => Some(path),
+        false => logo_dir().map(|dir| dir.join(path))
+    }
+}
+
+pub fn load_svg(bytes: &[u8], height: u32) -> anyhow::Result<ColorImage> {
+    egui_extras::image::load_svg_bytes_with_size(bytes, FitTo::Height(height))
+        .map_err(|e| anyhow!(e))
+}
+
+pub fn load_image_file(path: &Path, height: u32) -> anyhow::Result<ColorImage> {
+    let is_svg = path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("svg"));
+    if is_svg {
+        let bytes = std::fs::read(path).with_context(|| format!("Unable to read {}", path.display()))?;
+        return load_svg(&bytes, height);
+    }
+
+    let mut image = image::open(path).with_context(|| format!("Unable to read {}", path.display()))?;
+    if image.height() > height {
+        image = image.resize(u32::MAX, height, image::imageops::FilterType::Triangle);
+    }
+    let image = image.to_rgba8();
+    let size = [image.width() as usize, image.height() as usize];
+    Ok(ColorImage::from_rgba_unmultiplied(size, image.as_raw()))
+}
+
+pub fn save_png(path: &Path, image: &ColorImage) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("Unable to create {}", dir.display()))?;
+    }
+
+    let pixels: Vec<u8> = image.pixels.iter().flat_map(|pixel| pixel.to_srgba_unmultiplied()).collect();
+    let [width, height] = image.size;
+    image::save_buffer(path, &pixels, width as u32, height as u32, image::ColorType::Rgba8)
+        .with_context(|| format!("Unable to write {}", path.display()))
+}
@@ -0,0 +1,632 @@
+use std::io::{self, Stdout, Write};
+use std::time::Duration;
+use crossterm::{cursor, event, execute, queue, terminal};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{self, Color, Stylize};
+use eframe::egui::Color32;
+use strum::IntoEnumIterator;
+
+use crate::AppState;
+use crate::action::Action;
+use crate::audio::Audio;
+use crate::bjj_match::BJJMatch;
+use crate::bjj_match::competitor::CompetitorNumber;
+use crate::bjj_match::match_information::MatchInformation;
+use crate::bjj_match::match_result::WinMethod;
+use crate::bjj_match::match_time::MatchTime;
+use crate::event::EventSettings;
+use crate::keymap::{KeyChord, Keymap};
+use crate::persistence::MatchStore;
+use crate::ui::ColorScheme;
+use crate::ui::theme::ThemeSettings;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const SETUP_FIELDS: [&str; 16] = [
+    "Competitor One First Name",
+    "Competitor One Last Name",
+    "Competitor One Team",
+    "Competitor Two First Name",
+    "Competitor Two Last Name",
+    "Competitor Two Team",
+    "Match Duration (mins)",
+    "Ruleset",
+    "Age Class",
+    "Gender",
+    "Belt",
+    "Weight Class",
+    "Round",
+    "Overtime",
+    "Overtime Duration (mins)",
+    "Overtime Periods",
+];
+
+enum Prompt {
+    None,
+    Restore(Box<BJJMatch>),
+    Setup(usize),
+    ResultWinner,
+    ResultMethod(CompetitorNumber),
+    RefereeDecision,
+}
+
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter(stdout: &mut Stdout) -> anyhow::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+pub struct TuiScoreboard {
+    bjj_match: BJJMatch,
+    app_state: AppState,
+    prompt: Prompt,
+    color_scheme: ColorScheme,
+    event: EventSettings,
+    keymap: Keymap,
+    audio: Audio,
+    match_store: MatchStore,
+    save_error: Option<String>,
+    quit: bool,
+}
+
+impl Default for TuiScoreboard {
+    fn default() -> Self {
+        Self {
+            bjj_match: Default::default(),
+            app_state: AppState::NewMatchDialog,
+            prompt: Prompt::Setup(0),
+            color_scheme: Default::default(),
+            event: Default::default(),
+            keymap: Default::default(),
+            audio: Default::default(),
+            match_store: Default::default(),
+            save_error: None,
+            quit: false,
+        }
+    }
+}
+
+pub fn run() -> anyhow::Result<()> {
+    let mut scoreboard = TuiScoreboard::default();
+    scoreboard.setup();
+
+    let mut stdout = io::stdout();
+    let _guard = TerminalGuard::enter(&mut stdout)?;
+
+    while !scoreboard.quit {
+        scoreboard.update();
+        scoreboard.draw(&mut stdout)?;
+
+        if event::poll(POLL_INTERVAL)? {
+            match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => scoreboard.handle_key(key),
+                Event::Resize(_, _) => queue!(stdout, terminal::Clear(terminal::ClearType::All))?,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl TuiScoreboard {
+    fn setup(&mut self) {
+        self.audio.init();
+        self.event = EventSettings::load();
+        self.color_scheme = ThemeSettings::load().get_current().colors;
+        self.keymap = Keymap::load().0;
+
+        if let Some(saved) = self.match_store.load() {
+            if saved.time.started && !saved.is_finished() {
+                self.prompt = Prompt::Restore(Box::new(saved));
+            }
+        }
+    }
+
+    fn update(&mut self) {
+        if !matches!(self.prompt, Prompt::Restore(_)) {
+            self.save_error = self.match_store.save(&self.bjj_match)
+                .err()
+                .map(|e| format!("Error saving match: {:#}", e));
+        }
+
+        if let AppState::InProgress = self.app_state {
+            if self.bjj_match.time.running && self.bjj_match.time.get_remaining_time_milliseconds() == 0 {
+                self.audio.play_air_horn();
+                self.bjj_match.expire();
+            }
+        }
+
+        if let Some((competitor, _)) = self.bjj_match.expire_stoppages() {
+            self.audio.play_air_horn();
+            self.prompt = Prompt::ResultMethod(competitor.opponent());
+        }
+
+        if self.bjj_match.is_finished() {
+            self.app_state = AppState::Finished;
+            if matches!(self.prompt, Prompt::ResultWinner | Prompt::ResultMethod(_) | Prompt::RefereeDecision) {
+                self.prompt = Prompt::None;
+            }
+        } else if self.bjj_match.referee_decision_required {
+            self.app_state = AppState::AwaitingDecision;
+            self.prompt = Prompt::RefereeDecision;
+        } else if let AppState::Finished | AppState::AwaitingDecision = self.app_state {
+            if let Prompt::RefereeDecision = self.prompt {
+                self.prompt = Prompt::None;
+            }
+            self.app_state = match self.bjj_match.time.started {
+                true => AppState::InProgress,
+                false => AppState::Ready
+            };
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        if control && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        match &self.prompt {
+            Prompt::None => {},
+            Prompt::Restore(_) => {
+                self.handle_restore_key(key);
+                return;
+            },
+            Prompt::Setup(field) => {
+                let field = *field;
+                self.handle_setup_key(field, key);
+                return;
+            },
+            Prompt::ResultWinner | Prompt::ResultMethod(_) | Prompt::RefereeDecision => {
+                self.handle_result_key(key);
+                return;
+            }
+        }
+
+        let Some(chord) = key_chord(key) else {
+            return;
+        };
+        if chord.is_reserved() {
+            if !matches!(self.app_state, AppState::InProgress) {
+                self.prompt = Prompt::Setup(0);
+            }
+            return;
+        }
+
+        if let Some(action) = self.keymap.action_for(&chord) {
+            self.apply_action(action);
+        }
+    }
+
+    fn handle_restore_key(&mut self, key: KeyEvent) {
+        let restore = match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => true,
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => false,
+            _ => return
+        };
+
+        if let Prompt::Restore(interrupted) = std::mem::replace(&mut self.prompt, Prompt::Setup(0)) {
+            if restore {
+                self.bjj_match = *interrupted;
+                self.app_state = AppState::InProgress;
+                self.prompt = Prompt::None;
+            } else {
+                self.match_store.clear();
+            }
+        }
+    }
+
+    fn handle_setup_key(&mut self, field: usize, key: KeyEvent) {
+        let locked = self.bjj_match.time.started && is_locked_setup_field(field);
+        let info = &mut self.bjj_match.info;
+
+        match key.code {
+            KeyCode::Enter => {
+                info.match_time_minutes = info.match_time_minutes.max(1);
+                if let AppState::NewMatchDialog = self.app_state {
+                    self.app_state = AppState::Ready;
+                }
+                self.prompt = Prompt::None;
+            },
+            KeyCode::Esc if !matches!(self.app_state, AppState::NewMatchDialog) => self.prompt = Prompt::None,
+            KeyCode::Tab | KeyCode::Down => self.prompt = Prompt::Setup((field + 1) % SETUP_FIELDS.len()),
+            KeyCode::BackTab | KeyCode::Up => self.prompt = Prompt::Setup((field + SETUP_FIELDS.len() - 1) % SETUP_FIELDS.len()),
+            KeyCode::Left | KeyCode::Right | KeyCode::Backspace | KeyCode::Char(_) if locked => {},
+            KeyCode::Left | KeyCode::Right => {
+                let backwards = key.code == KeyCode::Left;
+                match field {
+                    7 => info.ruleset = cycle(info.ruleset, backwards),
+                    9 => info.division.gender = cycle(info.division.gender, backwards),
+                    10 => info.division.belt = cycle(info.division.belt, backwards),
+                    13 => info.overtime.mode = cycle(info.overtime.mode, backwards),
+                    14 => info.overtime.period_minutes = step(info.overtime.period_minutes, backwards, 1, 10),
+                    15 => info.overtime.max_periods = step(info.overtime.max_periods, backwards, 1, 5),
+                    _ => {}
+                }
+            },
+            KeyCode::Backspace => match field {
+                6 => info.match_time_minutes /= 10,
+                7 | 9 | 10 | 13..=15 => {},
+                _ => {
+                    setup_text(info, field).pop();
+                }
+            },
+            KeyCode::Char(c) => match field {
+                6 => {
+                    if let Some(digit) = c.to_digit(10) {
+                        let minutes = info.match_time_minutes * 10 + digit as usize;
+                        if minutes <= 30 {
+                            info.match_time_minutes = minutes;
+                        }
+                    }
+                },
+                7 | 9 | 10 | 13..=15 => {},
+                _ => setup_text(info, field).push(c)
+            },
+            _ => {}
+        }
+    }
+
+    fn handle_result_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc {
+            if !matches!(self.prompt, Prompt::RefereeDecision) {
+                self.prompt = Prompt::None;
+            }
+            return;
+        }
+
+        let KeyCode::Char(c) = key.code else {
+            return;
+        };
+        let Some(number) = c.to_digit(10).map(|digit| digit as usize) else {
+            return;
+        };
+
+        match self.prompt {
+            Prompt::ResultWinner | Prompt::RefereeDecision => {
+                let winner = match number {
+                    1 => CompetitorNumber::One,
+                    2 => CompetitorNumber::Two,
+                    _ => return
+                };
+                if let Prompt::RefereeDecision = self.prompt {
+                    self.bjj_match.record_referee_decision(winner);
+                    self.prompt = Prompt::None;
+                } else {
+                    self.prompt = Prompt::ResultMethod(winner);
+                }
+            },
+            Prompt::ResultMethod(winner) => {
+                if let Some(method) = WinMethod::manual().nth(number.wrapping_sub(1)) {
+                    self.bjj_match.finish(winner, method);
+                    self.prompt = Prompt::None;
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn apply_action(&mut self, action: Action) {
+        match action {
+            Action::AddPoints(competitor, points) => self.bjj_match.add_points(points, competitor),
+            Action::SubtractPoint(competitor) => self.bjj_match.subtract_point(competitor),
+            Action::AddAdvantage(competitor) => self.bjj_match.add_advantage(competitor),
+            Action::SubtractAdvantage(competitor) => self.bjj_match.subtract_advantage(competitor),
+            Action::AddPenalty(competitor) => self.bjj_match.add_penalty(competitor),
+            Action::SubtractPenalty(competitor) => self.bjj_match.subtract_penalty(competitor),
+            Action::Win(competitor, method) => self.bjj_match.finish(competitor, method),
+            Action::RecordResult => {
+                if !self.bjj_match.is_frozen() {
+                    self.bjj_match.stop();
+                    self.prompt = Prompt::ResultWinner;
+                }
+            },
+            Action::StartStop => {
+                match self.app_state {
+                    AppState::NewMatchDialog | AppState::AwaitingDecision => {},
+                    AppState::Finished => {
+                        self.new_match();
+                    },
+                    AppState::Ready => {
+                        self.audio.play_air_horn();
+                        self.bjj_match.start();
+                        self.app_state = AppState::InProgress
+                    },
+                    AppState::InProgress => {
+                        self.bjj_match.toggle_start_stop();
+                    }
+                }
+            },
+            Action::NewMatch => {
+                if let AppState::Finished | AppState::Ready = self.app_state {
+                    self.new_match();
+                }
+            },
+            Action::AdjustClock(seconds) => self.bjj_match.adjust_clock(seconds),
+            Action::SetRemainingTime(millis) => self.bjj_match.set_remaining_time(millis),
+            Action::ToggleStoppage(competitor, kind) => self.bjj_match.toggle_stoppage(competitor, kind),
+            Action::SetEbiPosition(position) => self.bjj_match.set_ebi_position(position),
+            Action::RecordEbiOutcome(outcome) => self.bjj_match.record_ebi_outcome(outcome),
+            Action::Undo => self.bjj_match.undo(),
+            Action::Redo => self.bjj_match.redo(),
+            Action::ToggleFullscreen => {}
+        }
+    }
+
+    fn new_match(&mut self) {
+        self.bjj_match.reset();
+        self.match_store.clear();
+        self.app_state = AppState::NewMatchDialog;
+        self.prompt = Prompt::Setup(0);
+    }
+
+    fn draw(&self, stdout: &mut Stdout) -> anyhow::Result<()> {
+        let (width, height) = terminal::size()?;
+        let width = width as usize;
+        let mut lines: Vec<(String, Color, Color)> = Vec::new();
+        let default_line = |text: String| (text, Color::Reset, Color::Reset);
+
+        match &self.prompt {
+            Prompt::Restore(interrupted) => {
+                lines.push(default_line("Restore Interrupted Match".to_owned()));
+                lines.push(default_line(String::new()));
+                lines.push(default_line(format!("{} vs {} was interrupted with {} remaining.",
+                    interrupted.info.competitor_one.get_display_name(),
+                    interrupted.info.competitor_two.get_display_name(),
+                    interrupted.time.get_remaining_time_string())));
+                lines.push(default_line("Restore it? (y/n)".to_owned()));
+            },
+            Prompt::Setup(selected) => {
+                let info = &self.bjj_match.info;
+                lines.push(default_line("Match Settings".to_owned()));
+                lines.push(default_line(String::new()));
+                for (index, label) in SETUP_FIELDS.iter().enumerate() {
+                    let value = match index {
+                        0 => info.competitor_one.first_name.clone(),
+                        1 => info.competitor_one.last_name.clone(),
+                        2 => info.competitor_one.team_name.clone(),
+                        3 => info.competitor_two.first_name.clone(),
+                        4 => info.competitor_two.last_name.clone(),
+                        5 => info.competitor_two.team_name.clone(),
+                        6 => info.match_time_minutes.to_string(),
+                        7 => format!("< {} >", info.ruleset.get_display_name()),
+                        8 => info.division.age_class.clone(),
+                        9 => format!("< {:?} >", info.division.gender),
+                        10 => format!("< {:?} >", info.division.belt),
+                        11 => info.division.weight_class.clone(),
+                        12 => info.round.clone(),
+                        13 => format!("< {} >", info.overtime.mode.get_display_name()),
+                        14 => format!("< {} >", info.overtime.period_minutes),
+                        _ => format!("< {} >", info.overtime.max_periods)
+                    };
+                    let marker = if index == *selected { ">" } else { " " };
+                    let locked = self.bjj_match.time.started && is_locked_setup_field(index);
+                    let note = if locked { "  (locked once the match has started)" } else { "" };
+                    lines.push(default_line(format!("{} {:<28}{}{}", marker, label, value, note)));
+                }
+                lines.push(default_line(String::new()));
+                lines.push(default_line("Tab/Up/Down: move   Left/Right: change option   Enter: done   Ctrl+C: quit".to_owned()));
+            },
+            _ => self.scoreboard_lines(width, &mut lines)
+        }
+
+        for row in 0..height as usize {
+            let (text, fg, bg) = lines.get(row).cloned().unwrap_or_else(|| default_line(String::new()));
+            let text: String = format!("{:<width$}", text, width = width).chars().take(width).collect();
+            queue!(stdout, cursor::MoveTo(0, row as u16), style::PrintStyledContent(text.with(fg).on(bg)))?;
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    fn scoreboard_lines(&self, width: usize, lines: &mut Vec<(String, Color, Color)>) {
+        let score = self.bjj_match.get_score();
+        let info = &self.bjj_match.info;
+        let default_line = |text: String| (text, Color::Reset, Color::Reset);
+
+        lines.push(default_line(format!(" {}  {}", self.event.get_heading(info), info.ruleset.get_display_name())));
+        lines.push(default_line(format!(" {}", self.event.get_subheading(info))));
+        lines.push(default_line(String::new()));
+
+        for (competitor, bg, fg) in [
+            (CompetitorNumber::One, self.color_scheme.competitor_one_points_bg, self.color_scheme.competitor_one_points),
+            (CompetitorNumber::Two, self.color_scheme.competitor_two_points_bg, self.color_scheme.competitor_two_points)
+        ] {
+            let details = info.get_competitor(competitor);
+            let player_score = score.get_competitor_score(competitor);
+            let stats = format!("Adv. {:>2}  Pen. {:>2}  {:>3} ", player_score.advantages, player_score.penalties, player_score.points);
+            let name = format!(" {}", details.get_display_name());
+            let padding = width.saturating_sub(name.chars().count() + stats.len());
+            lines.push((String::new(), to_color(fg), to_color(bg)));
+            lines.push((format!("{}{}{}", name, " ".repeat(padding), stats), to_color(fg), to_color(bg)));
+            lines.push((format!(" {}", details.team_name), to_color(fg), to_color(bg)));
+            lines.push((String::new(), to_color(fg), to_color(bg)));
+        }
+
+        lines.push(default_line(String::new()));
+        let time = match self.bjj_match.get_period_name() {
+            Some(period) => format!("{}  {}", period, self.bjj_match.time.get_remaining_time_string()),
+            None => self.bjj_match.time.get_remaining_time_string()
+        };
+        lines.push((format!("{:^width$}", time, width = width), to_color(self.color_scheme.time), Color::Reset));
+        for timer in self.bjj_match.stoppages.running() {
+            let stoppage = format!("{} {}: {}", timer.kind.get_display_name(),
+                info.get_competitor(timer.competitor).get_display_name(), timer.time.get_remaining_time_string());
+            lines.push((format!("{:^width$}", stoppage, width = width), to_color(self.color_scheme.stoppage_timer), Color::Reset));
+        }
+        if let Some(turn) = self.bjj_match.get_ebi_turn_description() {
+            lines.push((format!("{:^width$}", turn, width = width), to_color(self.color_scheme.time), Color::Reset));
+        }
+        for competitor in [CompetitorNumber::One, CompetitorNumber::Two] {
+            let summary = self.bjj_match.ebi.get_summary(competitor);
+            if !summary.is_empty() {
+                lines.push(default_line(format!(" {}: {}", info.get_competitor(competitor).get_display_name(), summary.join("  "))));
+            }
+        }
+        lines.push(default_line(String::new()));
+
+        if let Some(result) = &self.bjj_match.result {
+            lines.push((format!(" {} wins by {}{} ({})",
+                info.get_competitor(result.winner).get_display_name(),
+                result.method.get_display_name(),
+                self.bjj_match.get_period_name().map(|period| format!(" in {}", period)).unwrap_or_default(),
+                MatchTime::format_millis(result.time_elapsed_millis)), to_color(self.color_scheme.winner_banner), Color::Reset));
+        } else {
+            lines.push(default_line(format!(" {:?}", self.bjj_match.get_match_state())));
+        }
+        if let Some(error) = &self.save_error {
+            lines.push(default_line(format!(" {}", error)));
+        }
+        lines.push(default_line(String::new()));
+
+        match self.prompt {
+            Prompt::ResultWinner => {
+                lines.push(default_line(format!(" Record result - winner: 1) {}  2) {}  (Esc to cancel)",
+                    info.competitor_one.get_display_name(), info.competitor_two.get_display_name())));
+            },
+            Prompt::ResultMethod(winner) => {
+                let methods: Vec<String> = WinMethod::manual()
+                    .enumerate()
+                    .map(|(index, method)| format!("{}) {}", index + 1, method.get_display_name()))
+                    .collect();
+                lines.push(default_line(format!(" {} wins by: {}  (Esc to cancel)",
+                    info.get_competitor(winner).get_display_name(), methods.join("  "))));
+            },
+            Prompt::RefereeDecision => {
+                lines.push(default_line(format!(" The scores are level. Referee decision: 1) {}  2) {}",
+                    info.competitor_one.get_display_name(), info.competitor_two.get_display_name())));
+            },
+            _ => {
+                let mut help: Vec<String> = Action::all().into_iter()
+                    .filter_map(|action| {
+                        let chords: Vec<String> = self.keymap.chords_for(action).iter().map(|chord| chord.to_string()).collect();
+                        match chords.is_empty() {
+                            true => None,
+                            false => Some(format!("{}: {}", chords.join("/"), action.get_display_name()))
+                        }
+                    })
+                    .collect();
+                help.push("M: Match Setup".to_owned());
+                help.push("Ctrl+C: Quit".to_owned());
+
+                let mut line = String::new();
+                for entry in help {
+                    if !line.is_empty() && line.chars().count() + entry.chars().count() + 2 > width {
+                        lines.push(default_line(line));
+                        line = String::new();
+                    }
+                    line.push_str(&format!(" {} ", entry));
+                }
+                lines.push(default_line(line));
+            }
+        }
+    }
+}
+
+fn is_locked_setup_field(field: usize) -> bool {
+    field >= 6
+}
+
+fn cycle<T: IntoEnumIterator + PartialEq + Copy>(value: T, backwards: bool) -> T {
+    let values: Vec<T> = T::iter().collect();
+    let index = values.iter().position(|candidate| *candidate == value).unwrap_or_default();
+    let offset = if backwards { values.len() - 1 } else { 1 };
+    values[(index + offset) % values.len()]
+}
+
+fn step(value: usize, backwards: bool, min: usize, max: usize) -> usize {
+    if backwards {
+        value.saturating_sub(1).max(min)
+    } else {
+        (value + 1).min(max)
+    }
+}
+
+fn setup_text(info: &mut MatchInformation, field: usize) -> &mut String {
+    match field {
+        0 => &mut info.competitor_one.first_name,
+        1 => &mut info.competitor_one.last_name,
+        2 => &mut info.competitor_one.team_name,
+        3 => &mut info.competitor_two.first_name,
+        4 => &mut info.competitor_two.last_name,
+        5 => &mut info.competitor_two.team_name,
+        8 => &mut info.division.age_class,
+        11 => &mut info.division.weight_class,
+        _ => &mut info.round
+    }
+}
+
+fn key_chord(key: KeyEvent) -> Option<KeyChord> {
+    let name = KeyChord::parse(&key_name(key.code)?).ok()?.key;
+    let shift = match key.code {
+        KeyCode::Char(c) => key.modifiers.contains(KeyModifiers::SHIFT) || c.is_ascii_uppercase(),
+        _ => key.modifiers.contains(KeyModifiers::SHIFT)
+    };
+    Some(KeyChord::with_modifiers(&name,
+        key.modifiers.contains(KeyModifiers::CONTROL),
+        shift,
+        key.modifiers.contains(KeyModifiers::ALT)))
+}
+
+fn key_name(code: KeyCode) -> Option<String> {
+    match code {
+        KeyCode::Char(' ') => Some("Space".to_owned()),
+        KeyCode::Char('-') => Some("Minus".to_owned()),
+        KeyCode::Char('+') | KeyCode::Char('=') => Some("Plus".to_owned()),
+        KeyCode::Char(c) => Some(c.to_ascii_uppercase().to_string()),
+        KeyCode::F(n) => Some(format!("F{}", n)),
+        KeyCode::Enter => Some("Enter".to_owned()),
+        KeyCode::Tab => Some("Tab".to_owned()),
+        KeyCode::Backspace => Some("Backspace".to_owned()),
+        KeyCode::Up => Some("Up".to_owned()),
+        KeyCode::Down => Some("Down".to_owned()),
+        KeyCode::Left => Some("Left".to_owned()),
+        KeyCode::Right => Some("Right".to_owned()),
+        KeyCode::Esc => Some("Escape".to_owned()),
+        KeyCode::Insert => Some("Insert".to_owned()),
+        KeyCode::Delete => Some("Delete".to_owned()),
+        KeyCode::Home => Some("Home".to_owned()),
+        KeyCode::End => Some("End".to_owned()),
+        KeyCode::PageUp => Some("PageUp".to_owned()),
+        KeyCode::PageDown => Some("PageDown".to_owned()),
+        _ => None
+    }
+}
+
+fn to_color(color: Color32) -> Color {
+    Color::Rgb { r: color.r(), g: color.g(), b: color.b() }
+}
+
+#[cfg(test)]
+mod tests {
+    use eframe::egui;
+    use super::*;
+
+    #[test]
+    fn builds_the_same_chords_as_the_gui() {
+        let cases = [
+            (KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE), egui::Key::Q, egui::Modifiers::NONE),
+            (KeyEvent::new(KeyCode::Char('Q'), KeyModifiers::SHIFT), egui::Key::Q, egui::Modifiers::SHIFT),
+            (KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL), egui::Key::Z, egui::Modifiers::COMMAND),
+            (KeyEvent::new(KeyCode::Char('+'), KeyModifiers::SHIFT), egui::Key::PlusEquals, egui::Modifiers::SHIFT),
+            (KeyEvent::new(KeyCode::Char('-'), KeyModifiers::NONE), egui::Key::Minus, egui::Modifiers::NONE),
+        ];
+        for (event, key, modifiers) in cases {
+            assert_eq!(key_chord(event), Some(KeyChord::from_egui(key, modifiers)));
+        }
+    }
+}
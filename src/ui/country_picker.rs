@@ -0,0 +1,104 @@
+use std::hash::Hash;
+use eframe::egui::{self, Key, Vec2};
+use crate::flags::{Country, FlagTextures, THUMBNAIL_FLAG_HEIGHT};
+
+const THUMBNAIL_HEIGHT: f32 = 14.0;
+const LIST_HEIGHT: f32 = 240.0;
+
+#[derive(Clone, Default)]
+struct PickerState {
+    query: String,
+    highlighted: usize,
+}
+
+pub fn country_picker(ui: &mut egui::Ui, id_source: impl Hash, country: &mut Country, flags: &mut FlagTextures) -> egui::Response {
+    let id = ui.make_persistent_id(id_source);
+    let popup_id = id.with("popup");
+
+    let button = match thumbnail(flags, *country) {
+        Some(image) => egui::Button::image_and_text(image, country.get_name()),
+        None => egui::Button::new(country.get_name())
+    };
+    let mut response = ui.add(button.min_size(Vec2 { x: 160.0, y: 0.0 }));
+
+    if response.clicked() {
+        ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+        ui.data_mut(|data| data.insert_temp(id, PickerState::default()));
+    }
+
+    let mut selected = None;
+
+    egui::popup_below_widget(ui, popup_id, &response, |ui| {
+        ui.set_min_width(260.0);
+        let mut state = ui.data_mut(|data| data.get_temp::<PickerState>(id)).unwrap_or_default();
+
+        let search = ui.add(egui::TextEdit::singleline(&mut state.query).hint_text("Name or code"));
+        search.request_focus();
+        if search.changed() {
+            state.highlighted = 0;
+        }
+
+        let results = Country::search(&state.query);
+        let mut moved = false;
+        if ui.input(|i| i.key_pressed(Key::ArrowDown)) && state.highlighted + 1 < results.len() {
+            state.highlighted += 1;
+            moved = true;
+        }
+        if ui.input(|i| i.key_pressed(Key::ArrowUp)) && state.highlighted > 0 {
+            state.highlighted -= 1;
+            moved = true;
+        }
+        if ui.input(|i| i.key_pressed(Key::Enter)) {
+            selected = results.get(state.highlighted).copied();
+        }
+
+        if results.is_empty() {
+            ui.label("No matching countries");
+        }
+
+        let row_height = ui.spacing().interact_size.y.max(THUMBNAIL_HEIGHT);
+        let mut scroll_area = egui::ScrollArea::vertical().max_height(LIST_HEIGHT);
+        if moved {
+            let row_top = state.highlighted as f32 * (row_height + ui.spacing().item_spacing.y);
+            scroll_area = scroll_area.vertical_scroll_offset((row_top - LIST_HEIGHT / 2.0).max(0.0));
+        }
+        scroll_area.show_rows(ui, row_height, results.len(), |ui, rows| {
+            for index in rows {
+                let result = &results[index];
+                let row = ui.horizontal(|ui| {
+                    match thumbnail(flags, *result) {
+                        Some(image) => {
+                            ui.add(image);
+                        },
+                        None => {
+                            let (rect, _) = ui.allocate_exact_size(Vec2 { x: THUMBNAIL_HEIGHT * 1.5, y: THUMBNAIL_HEIGHT }, egui::Sense::hover());
+                            ui.painter().rect_filled(rect, egui::Rounding::ZERO, ui.visuals().faint_bg_color);
+                        }
+                    }
+                    ui.selectable_label(index == state.highlighted || result == country,
+                        format!("{} ({})", result.get_name(), result.get_code()))
+                }).inner;
+
+                if row.clicked() {
+                    selected = Some(*result);
+                }
+            }
+        });
+
+        ui.data_mut(|data| data.insert_temp(id, state));
+    });
+
+    if let Some(result) = selected {
+        *country = result;
+        response.mark_changed();
+        ui.memory_mut(|memory| memory.close_popup());
+    }
+
+    response
+}
+
+fn thumbnail(flags: &mut FlagTextures, country: Country) -> Option<egui::Image<'static>> {
+    let handle = flags.get(country, THUMBNAIL_FLAG_HEIGHT)?;
+    let size = handle.size_vec2();
+    Some(egui::Image::from_texture((handle.id(), size * (THUMBNAIL_HEIGHT / size.y))))
+}
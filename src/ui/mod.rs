@@ -1,5 +1,12 @@
+pub mod country_picker;
+pub mod theme;
+
+use std::collections::BTreeMap;
 use eframe::egui::Color32;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
 
+#[derive(Clone, PartialEq)]
 pub struct ColorScheme {
     pub competitor_one_bg: Color32,
     pub competitor_one_name: Color32,
@@ -23,6 +30,9 @@ pub struct ColorScheme {
     pub time: Color32,
     pub fight_info_heading: Color32,
     pub fight_info_sub_heading: Color32,
+    pub winner_banner: Color32,
+    pub flag_placeholder: Color32,
+    pub stoppage_timer: Color32,
 }
 
 impl Default for ColorScheme {
@@ -50,10 +60,94 @@ impl Default for ColorScheme {
             time: Color32::from_rgb(255, 255, 180),
             fight_info_heading: Color32::from_rgb(200, 200, 140),
             fight_info_sub_heading: Color32::from_rgb(255, 255, 255),
+            winner_banner: Color32::from_rgb(255, 255, 255),
+            flag_placeholder: Color32::from_rgb(64, 64, 64),
+            stoppage_timer: Color32::from_rgb(255, 96, 96),
+        }
+    }
+}
+
+impl ColorScheme {
+    pub fn colors_mut(&mut self) -> [(&'static str, &mut Color32); 25] {
+        [
+            ("competitor_one_bg", &mut self.competitor_one_bg),
+            ("competitor_one_name", &mut self.competitor_one_name),
+            ("competitor_one_team", &mut self.competitor_one_team),
+            ("competitor_one_adv_bg", &mut self.competitor_one_adv_bg),
+            ("competitor_one_adv", &mut self.competitor_one_adv),
+            ("competitor_one_pen_bg", &mut self.competitor_one_pen_bg),
+            ("competitor_one_pen", &mut self.competitor_one_pen),
+            ("competitor_one_points_bg", &mut self.competitor_one_points_bg),
+            ("competitor_one_points", &mut self.competitor_one_points),
+            ("competitor_two_bg", &mut self.competitor_two_bg),
+            ("competitor_two_name", &mut self.competitor_two_name),
+            ("competitor_two_team", &mut self.competitor_two_team),
+            ("competitor_two_adv_bg", &mut self.competitor_two_adv_bg),
+            ("competitor_two_adv", &mut self.competitor_two_adv),
+            ("competitor_two_pen_bg", &mut self.competitor_two_pen_bg),
+            ("competitor_two_pen", &mut self.competitor_two_pen),
+            ("competitor_two_points_bg", &mut self.competitor_two_points_bg),
+            ("competitor_two_points", &mut self.competitor_two_points),
+            ("bottom_pane_bg", &mut self.bottom_pane_bg),
+            ("time", &mut self.time),
+            ("fight_info_heading", &mut self.fight_info_heading),
+            ("fight_info_sub_heading", &mut self.fight_info_sub_heading),
+            ("winner_banner", &mut self.winner_banner),
+            ("flag_placeholder", &mut self.flag_placeholder),
+            ("stoppage_timer", &mut self.stoppage_timer),
+        ]
+    }
+}
+
+impl Serialize for ColorScheme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut colors = self.clone();
+        let map: BTreeMap<&str, String> = colors.colors_mut().into_iter()
+            .map(|(name, color)| (name, to_hex(*color)))
+            .collect();
+        map.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorScheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = BTreeMap::<String, String>::deserialize(deserializer)?;
+        let mut color_scheme = ColorScheme::default();
+        for (name, color) in color_scheme.colors_mut() {
+            if let Some(hex) = map.get(name) {
+                *color = from_hex(hex).ok_or_else(|| D::Error::custom(format!("\"{}\" is not a valid colour for {}", hex, name)))?;
+            }
         }
+        Ok(color_scheme)
     }
 }
 
+pub fn to_hex(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    match a {
+        255 => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        _ => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
+
+pub fn from_hex(hex: &str) -> Option<Color32> {
+    let hex = hex.trim().strip_prefix('#')?;
+    if !hex.is_ascii() || !(hex.len() == 6 || hex.len() == 8) {
+        return None;
+    }
+
+    let channels: Vec<u8> = (0..hex.len()).step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect::<Option<_>>()?;
+    match channels[..] {
+        [r, g, b] => Some(Color32::from_rgb(r, g, b)),
+        [r, g, b, a] => Some(Color32::from_rgba_unmultiplied(r, g, b, a)),
+        _ => None
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FontSizes {
     pub competitor_name: f32,
     pub competitor_team: f32,
@@ -65,6 +159,7 @@ pub struct FontSizes {
     pub time: f32,
     pub fight_info_heading: f32,
     pub fight_info_sub_heading: f32,
+    pub winner_banner: f32,
 }
 
 impl Default for FontSizes {
@@ -80,6 +175,25 @@ impl Default for FontSizes {
             time: 32.0,
             fight_info_heading: 32.0,
             fight_info_sub_heading: 28.0,
+            winner_banner: 40.0,
         }
     }
-}
\ No newline at end of file
+}
+
+impl FontSizes {
+    pub fn sizes_mut(&mut self) -> [(&'static str, &mut f32); 11] {
+        [
+            ("competitor_name", &mut self.competitor_name),
+            ("competitor_team", &mut self.competitor_team),
+            ("competitor_adv_label", &mut self.competitor_adv_label),
+            ("competitor_adv", &mut self.competitor_adv),
+            ("competitor_pen_label", &mut self.competitor_pen_label),
+            ("competitor_pen", &mut self.competitor_pen),
+            ("competitor_points", &mut self.competitor_points),
+            ("time", &mut self.time),
+            ("fight_info_heading", &mut self.fight_info_heading),
+            ("fight_info_sub_heading", &mut self.fight_info_sub_heading),
+            ("winner_banner", &mut self.winner_banner),
+        ]
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use eframe::egui::Color32;
+use serde::{Deserialize, Serialize};
+use crate::grid::LayoutKind;
+use crate::persistence;
+use crate::ui::{ColorScheme, FontSizes};
+
+const THEME_FILE: &str = "theme.json";
+pub const DEFAULT_THEME: &str = "Default";
+
+#[derive(Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub colors: ColorScheme,
+    pub font_sizes: FontSizes,
+    pub layout: LayoutKind,
+}
+
+impl Theme {
+    pub fn classic() -> Theme {
+        let green = Color32::from_rgb(0, 122, 61);
+        let yellow = Color32::from_rgb(250, 204, 0);
+        let colors = ColorScheme {
+            competitor_one_points_bg: green,
+            competitor_two_bg: Color32::from_rgb(0, 0, 0),
+            competitor_two_adv_bg: Color32::from_rgb(0, 0, 0),
+            competitor_two_pen_bg: Color32::from_rgb(0, 0, 0),
+            competitor_two_points_bg: yellow,
+            competitor_two_points: Color32::from_rgb(0, 0, 0),
+            time: Color32::from_rgb(255, 255, 255),
+            fight_info_heading: yellow,
+            ..Default::default()
+        };
+
+        Theme { colors, font_sizes: FontSizes::default(), layout: LayoutKind::default() }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSettings {
+    pub current: String,
+    pub themes: BTreeMap<String, Theme>,
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            current: DEFAULT_THEME.to_owned(),
+            themes: BTreeMap::from([
+                (DEFAULT_THEME.to_owned(), Theme::default()),
+                ("Classic".to_owned(), Theme::classic()),
+            ]),
+        }
+    }
+}
+
+impl ThemeSettings {
+    pub fn load() -> ThemeSettings {
+        let mut settings: ThemeSettings = persistence::load_config(THEME_FILE);
+        if settings.themes.is_empty() {
+            settings.themes = ThemeSettings::default().themes;
+        }
+        if !settings.themes.contains_key(&settings.current) {
+            settings.current = settings.themes.keys().next().cloned().unwrap_or_default();
+        }
+        settings
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        persistence::save_config(THEME_FILE, self)
+    }
+
+    pub fn get_current(&self) -> Theme {
+        self.themes.get(&self.current).cloned().unwrap_or_default()
+    }
+
+    pub fn store(&mut self, name: &str, theme: Theme) {
+        self.themes.insert(name.to_owned(), theme);
+        self.current = name.to_owned();
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        if self.themes.len() > 1 {
+            self.themes.remove(name);
+        }
+        if !self.themes.contains_key(&self.current) {
+            self.current = self.themes.keys().next().cloned().unwrap_or_default();
+        }
+    }
+}